@@ -0,0 +1,21 @@
+use std::env::current_dir;
+
+use cosmwasm_schema::{export_schema, schema_for, write_api};
+
+use rwa_stockholm_packages::msg::gold_swap::{
+    ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, ReceiveMsg, SudoMsg,
+};
+
+fn main() {
+    write_api! {
+        instantiate: InstantiateMsg,
+        execute: ExecuteMsg,
+        query: QueryMsg,
+        migrate: MigrateMsg,
+        sudo: SudoMsg,
+    }
+
+    // Swaps are triggered through the CW20 hook, so clients also need the embedded payload
+    let out_dir = current_dir().unwrap().join("schema");
+    export_schema(&schema_for!(ReceiveMsg), &out_dir);
+}
@@ -0,0 +1,60 @@
+use cosmwasm_std::{Decimal, Env, Storage};
+
+use crate::math::{abs_diff, bps_of};
+use crate::state::{Pair, RATE_UPDATES};
+use crate::ContractError;
+
+/// Enforces the pair's per-update change cap and per-window update limit on an admin rate
+/// change, and records the update. A rate set for the first time is never capped.
+pub fn check_rate_update(
+    storage: &mut dyn Storage,
+    env: &Env,
+    pair: &Pair,
+    proposed: Decimal,
+) -> Result<(), ContractError> {
+    let breaker = &pair.circuit_breaker;
+
+    if let Some(max_change_bps) = breaker.max_rate_change_bps {
+        let previous = pair.rate;
+        if !previous.is_zero() && abs_diff(proposed, previous) > bps_of(previous, max_change_bps)? {
+            return Err(ContractError::RateChangeTooLarge {
+                previous,
+                proposed,
+                max_change_bps,
+            });
+        }
+    }
+
+    let window_start = env
+        .block
+        .time
+        .seconds()
+        .saturating_sub(breaker.window_seconds);
+    let mut updates = RATE_UPDATES
+        .may_load(storage, pair.base_token.as_str())?
+        .unwrap_or_default();
+    updates.retain(|time| time.seconds() > window_start);
+    if let Some(max_updates) = breaker.max_updates_per_window {
+        if updates.len() as u32 >= max_updates {
+            return Err(ContractError::TooManyRateUpdates {
+                max_updates,
+                window_seconds: breaker.window_seconds,
+            });
+        }
+    }
+    updates.push(env.block.time);
+    RATE_UPDATES.save(storage, pair.base_token.as_str(), &updates)?;
+
+    Ok(())
+}
+
+/// Whether an oracle or feeder `rate` has moved far enough from the rate of the previous swap
+/// on the pair to pause it.
+pub fn should_auto_pause(pair: &Pair, rate: Decimal) -> Result<bool, ContractError> {
+    match (pair.circuit_breaker.auto_pause_bps, pair.reference_rate) {
+        (Some(threshold_bps), Some(reference)) => {
+            Ok(abs_diff(rate, reference) > bps_of(reference, threshold_bps)?)
+        }
+        _ => Ok(false),
+    }
+}
@@ -0,0 +1,1997 @@
+/*
+This contract is a swap desk for commodity tokens (gold, silver, platinum, carbon credits, ...) represented as CW20 tokens or native bank denoms on the CosmWasm platform. The admin registers a trading pair per commodity token against a quote token such as "OM", each with its own rate source, fees and limits, and users swap between the two sides of a pair at its current rate.
+*/
+
+use cosmwasm_std::{
+    entry_point, from_json, to_json_binary, Addr, Api, BankMsg, Binary, Coin, CosmosMsg, Decimal,
+    Deps, DepsMut, Empty, Env, MessageInfo, Order, QuerierWrapper, Response, StdResult, Storage,
+    Timestamp, Uint128, WasmMsg,
+};
+use cw2::set_contract_version;
+use cw20::{Cw20ExecuteMsg, Cw20QueryMsg, Cw20ReceiveMsg, TokenInfoResponse};
+use cw_storage_plus::Bound;
+use rwa_stockholm_packages::msg::gold_swap::{
+    Asset, AssetInfo, Attestation, CollectedFeesResponse, ConfigResponse, DcaPlan,
+    DcaPlansResponse, ExchangeRateResponse, ExecuteMsg, FeederInfo, FeedersResponse,
+    InstantiateMsg, LimitOrder, MigrateMsg, OrdersResponse, PairConfig, PairResponse, PairUpdate,
+    PairsResponse, PendingPairUpdate, PendingRate, PendingRateResponse, QueryMsg,
+    RateHistoryResponse, RateSource, ReceiveMsg, RemainingAllowanceResponse, ReserveBacking,
+    ReserveStatusResponse, ReservesResponse, ReverseSimulationResponse, SimulationResponse,
+    SudoMsg, TokenAmount, Trade, TradesResponse, TwapResponse,
+};
+use rwa_stockholm_packages::ownership::Role;
+use rwa_stockholm_packages::pause::Operation;
+
+use crate::circuit_breaker::{check_rate_update, should_auto_pause};
+use crate::dca::{create_plan, due_plans, plans_by_user, remove_plan, save_plan};
+use crate::history::{rate_history, record_rate, twap};
+use crate::limits::{record_volume, remaining_allowance, validate_volume_limits};
+use crate::math::{
+    bps_of_amount, validate_keeper_tip, validate_swap_params, SwapOutcome, MAX_TOKEN_DECIMALS,
+};
+use crate::oracle::{current_rate, latest_rate, validate_rate_source};
+use crate::orders::{next_crank_orders, order_book, orders_by_user, place_order, remove_order};
+use crate::proof_of_reserves::{check_backing, reserve_status};
+use crate::state::{
+    Pair, PriceSubmission, ScheduledRate, ASSETS, ATTESTATIONS, AUDITORS, COLLECTED_FEES,
+    DCA_PLANS, FEEDERS, MARKET_MAKERS, ORDERS, OWNERSHIP, PAIRS, PAUSE, PENDING_PAIR_UPDATES,
+    RESERVES, RESERVE_BACKING, SCHEDULED_RATES, SUBMISSIONS, TRADES,
+};
+use crate::swap::{reverse_simulate, route, simulate, Direction};
+use crate::timelock::{activate_scheduled_rate, cancel_scheduled_rate, schedule_rate};
+use crate::trades::{record_trade, swap_event, trades_by_user};
+use crate::ContractError;
+
+const CONTRACT_NAME: &str = "gold-om-swap";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let admin = OWNERSHIP.initialize(deps.storage, deps.api, &msg.admin)?;
+    PAUSE.initialize(deps.storage, deps.api, &msg.guardian)?;
+    for (role, address) in [
+        (Role::RateSetter, msg.rate_setter),
+        (Role::Pauser, msg.pauser),
+        (Role::Treasury, msg.treasury),
+    ] {
+        OWNERSHIP.set_role(deps.storage, deps.api, &admin, role, address)?;
+    }
+
+    // Pairs are added afterwards through `AddPair`
+
+    Ok(Response::new().add_attribute("method", "instantiate"))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    if let Some(operation) = operation(&msg) {
+        PAUSE.assert_not_paused(deps.storage, operation)?;
+    }
+    match msg {
+        ExecuteMsg::EmergencyPause { operation } => {
+            PAUSE.set_paused(deps.storage, &info.sender, operation, true)
+        }
+        ExecuteMsg::EmergencyUnpause { operation } => {
+            PAUSE.set_paused(deps.storage, &info.sender, operation, false)
+        }
+        ExecuteMsg::SetGuardian { guardian } => {
+            PAUSE.set_guardian(deps.storage, deps.api, &info.sender, &guardian)
+        }
+        ExecuteMsg::ProposeNewAdmin { new_admin } => {
+            OWNERSHIP.propose_admin(deps.storage, deps.api, &info.sender, &new_admin)
+        }
+        ExecuteMsg::AcceptAdmin {} => OWNERSHIP.accept_admin(deps.storage, &info.sender),
+        ExecuteMsg::SetRole { role, address } => {
+            OWNERSHIP.set_role(deps.storage, deps.api, &info.sender, role, address)
+        }
+        ExecuteMsg::AddPair { pair } => execute_add_pair(deps, info, pair),
+        ExecuteMsg::UpdatePair { base_token, update } => {
+            execute_update_pair(deps, env, info, base_token, update)
+        }
+        ExecuteMsg::DisablePair { base_token } => execute_disable_pair(deps, info, base_token),
+        ExecuteMsg::ApplyPairUpdate { base_token } => {
+            execute_apply_pair_update(deps, env, info, base_token)
+        }
+        ExecuteMsg::CancelPairUpdate { base_token } => {
+            execute_cancel_pair_update(deps, info, base_token)
+        }
+        ExecuteMsg::SetExchangeRate { base_token, rate } => {
+            execute_set_exchange_rate(deps, env, info, base_token, rate)
+        }
+        ExecuteMsg::ProposeRate {
+            base_token,
+            rate,
+            effective_at,
+        } => execute_propose_rate(deps, env, info, base_token, rate, effective_at),
+        ExecuteMsg::CancelRate { base_token } => execute_cancel_rate(deps, env, info, base_token),
+        ExecuteMsg::Pause { base_token } => execute_set_paused(deps, info, base_token, true),
+        ExecuteMsg::Unpause { base_token } => execute_set_paused(deps, info, base_token, false),
+        ExecuteMsg::AddMarketMaker { address } => {
+            execute_set_market_maker(deps, info, address, true)
+        }
+        ExecuteMsg::RemoveMarketMaker { address } => {
+            execute_set_market_maker(deps, info, address, false)
+        }
+        ExecuteMsg::WithdrawFees { to } => execute_withdraw_fees(deps, info, to),
+        ExecuteMsg::WithdrawReserve { token, amount, to } => {
+            execute_withdraw_reserve(deps, info, token, amount, to)
+        }
+        ExecuteMsg::AddFeeder { address } => execute_add_feeder(deps, info, address),
+        ExecuteMsg::RemoveFeeder { address } => execute_remove_feeder(deps, info, address),
+        ExecuteMsg::AddAuditor { address } => execute_set_auditor(deps, info, address, true),
+        ExecuteMsg::RemoveAuditor { address } => execute_set_auditor(deps, info, address, false),
+        ExecuteMsg::SetReserveBacking {
+            base_token,
+            backing,
+        } => execute_set_reserve_backing(deps, info, base_token, backing),
+        ExecuteMsg::PostAttestation {
+            base_token,
+            ounces,
+            report_hash,
+            reported_at,
+        } => execute_post_attestation(
+            deps,
+            env,
+            info,
+            base_token,
+            ounces,
+            report_hash,
+            reported_at,
+        ),
+        ExecuteMsg::SubmitPrice { base_token, price } => {
+            execute_submit_price(deps, env, info, base_token, price)
+        }
+        ExecuteMsg::Swap {
+            ask_token,
+            min_output,
+            deadline,
+        } => {
+            let offer = one_coin(&info)?;
+            execute_swap(
+                deps,
+                env,
+                info.sender,
+                offer,
+                ask_token,
+                min_output,
+                deadline,
+            )
+        }
+        ExecuteMsg::PlaceLimitOrder {
+            min_rate,
+            expires_at,
+        } => {
+            let offer = one_coin(&info)?;
+            execute_place_limit_order(deps, env, info.sender, offer, min_rate, expires_at)
+        }
+        ExecuteMsg::CancelOrder { id } => execute_cancel_order(deps, info, id),
+        ExecuteMsg::ExecuteOrders { base_token, limit } => {
+            execute_execute_orders(deps, env, base_token, limit)
+        }
+        ExecuteMsg::CreateDcaPlan {
+            base_token,
+            per_swap_amount,
+            interval_seconds,
+            max_swaps,
+        } => {
+            let deposit = one_coin(&info)?;
+            let plan = DcaPlanParams {
+                base_token,
+                per_swap_amount,
+                interval_seconds,
+                max_swaps,
+            };
+            execute_create_dca_plan(deps, env, info.sender, deposit, plan)
+        }
+        ExecuteMsg::ExecuteDue { limit } => execute_execute_due(deps, env, info.sender, limit),
+        ExecuteMsg::PauseDcaPlan { id } => execute_set_dca_plan_paused(deps, env, info, id, true),
+        ExecuteMsg::ResumeDcaPlan { id } => execute_set_dca_plan_paused(deps, env, info, id, false),
+        ExecuteMsg::CancelDcaPlan { id } => execute_cancel_dca_plan(deps, info, id),
+        ExecuteMsg::FundReserve {} => {
+            let deposits = info.funds.into_iter().map(native_asset).collect();
+            execute_fund_reserve(deps, info.sender, deposits)
+        }
+        ExecuteMsg::Receive(msg) => execute_receive(deps, env, info, msg),
+    }
+}
+
+/// Emergency pause covering `msg`, if any. CW20 hook messages are checked in `execute_receive`,
+/// and cancellations are never paused so escrowed funds can always be taken back.
+fn operation(msg: &ExecuteMsg) -> Option<Operation> {
+    match msg {
+        ExecuteMsg::Swap { .. }
+        | ExecuteMsg::PlaceLimitOrder { .. }
+        | ExecuteMsg::ExecuteOrders { .. }
+        | ExecuteMsg::CreateDcaPlan { .. }
+        | ExecuteMsg::ExecuteDue { .. } => Some(Operation::Swap),
+        ExecuteMsg::FundReserve {} => Some(Operation::Deposit),
+        ExecuteMsg::WithdrawFees { .. } | ExecuteMsg::WithdrawReserve { .. } => {
+            Some(Operation::Withdraw)
+        }
+        _ => None,
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
+    let binary = match msg {
+        QueryMsg::Config {} => to_json_binary(&query_config(deps)?),
+        QueryMsg::Ownership {} => to_json_binary(&OWNERSHIP.query(deps.storage)?),
+        QueryMsg::PauseInfo {} => to_json_binary(&PAUSE.query(deps.storage)?),
+        QueryMsg::Pair { base_token } => to_json_binary(&query_pair(deps, base_token)?),
+        QueryMsg::Pairs { start_after, limit } => {
+            to_json_binary(&query_pairs(deps, start_after, limit)?)
+        }
+        QueryMsg::ExchangeRate { base_token } => {
+            to_json_binary(&query_exchange_rate(deps, env, base_token)?)
+        }
+        QueryMsg::PendingRate {} => to_json_binary(&query_pending_rate(deps, env)?),
+        QueryMsg::PendingPairUpdate { base_token } => {
+            to_json_binary(&PENDING_PAIR_UPDATES.may_load(deps.storage, &base_token)?)
+        }
+        QueryMsg::RateHistory {
+            base_token,
+            start,
+            end,
+            limit,
+        } => to_json_binary(&query_rate_history(
+            deps, env, base_token, start, end, limit,
+        )?),
+        QueryMsg::Twap {
+            base_token,
+            window_seconds,
+        } => to_json_binary(&query_twap(deps, env, base_token, window_seconds)?),
+        QueryMsg::Simulation {
+            offer_asset,
+            ask_asset,
+            amount,
+        } => to_json_binary(&query_simulation(
+            deps,
+            env,
+            offer_asset,
+            ask_asset,
+            amount,
+        )?),
+        QueryMsg::ReverseSimulation {
+            ask_asset,
+            offer_asset,
+            amount,
+        } => to_json_binary(&query_reverse_simulation(
+            deps,
+            env,
+            ask_asset,
+            offer_asset,
+            amount,
+        )?),
+        QueryMsg::Feeders { base_token } => to_json_binary(&query_feeders(deps, base_token)?),
+        QueryMsg::ReserveStatus {} => to_json_binary(&query_reserve_status(deps, env)?),
+        QueryMsg::Trade { id } => to_json_binary(&query_trade(deps, id)?),
+        QueryMsg::TradesByUser {
+            user,
+            start_after,
+            limit,
+        } => to_json_binary(&query_trades_by_user(deps, user, start_after, limit)?),
+        QueryMsg::Order { id } => to_json_binary(&query_order(deps, id)?),
+        QueryMsg::OrderBook {
+            base_token,
+            start_after,
+            limit,
+        } => to_json_binary(&query_order_book(deps, base_token, start_after, limit)?),
+        QueryMsg::OrdersByUser {
+            user,
+            start_after,
+            limit,
+        } => to_json_binary(&query_orders_by_user(deps, user, start_after, limit)?),
+        QueryMsg::DcaPlan { id } => to_json_binary(&load_dca_plan(deps.storage, id)?),
+        QueryMsg::DcaPlansByUser {
+            user,
+            start_after,
+            limit,
+        } => to_json_binary(&query_dca_plans_by_user(deps, user, start_after, limit)?),
+        QueryMsg::CollectedFees {} => to_json_binary(&query_collected_fees(deps)?),
+        QueryMsg::Reserves {} => to_json_binary(&query_reserves(deps)?),
+        QueryMsg::RemainingAllowance {
+            base_token,
+            address,
+        } => to_json_binary(&query_remaining_allowance(deps, env, base_token, address)?),
+    };
+    Ok(binary?)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::new().add_attribute("method", "migrate"))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn sudo(deps: DepsMut, env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
+    match msg {
+        SudoMsg::SetExchangeRate { base_token, rate } => {
+            let pair = load_pair(deps.storage, &base_token)?;
+            update_exchange_rate(deps, env, pair, rate, "sudo_set_exchange_rate")
+        }
+    }
+}
+
+pub fn execute_add_pair(
+    deps: DepsMut,
+    info: MessageInfo,
+    config: PairConfig,
+) -> Result<Response, ContractError> {
+    OWNERSHIP.assert_admin(deps.storage, &info.sender)?;
+
+    let base_token = validate_asset(deps.api, config.base_token)?;
+    let quote_token = validate_asset(deps.api, config.quote_token)?;
+    if base_token.as_str() == quote_token.as_str() {
+        return Err(ContractError::InvalidToken {
+            token: quote_token.as_str().to_string(),
+        });
+    }
+    if PAIRS.has(deps.storage, base_token.as_str()) {
+        return Err(ContractError::PairExists {
+            token: base_token.as_str().to_string(),
+        });
+    }
+    validate_swap_params(&config.sell, config.swap_fee_bps)?;
+    validate_swap_params(&config.buy, config.swap_fee_bps)?;
+    validate_keeper_tip(config.keeper_tip_bps)?;
+    validate_volume_limits(&config.volume_limits)?;
+
+    let pair = Pair {
+        base_decimals: asset_decimals(&deps.querier, &base_token, config.base_decimals)?,
+        quote_decimals: asset_decimals(&deps.querier, &quote_token, config.quote_decimals)?,
+        base_token,
+        quote_token,
+        rate: Decimal::zero(), // Expecting the admin to set the rate in fixed mode
+        rate_updated_at: None,
+        rate_source: validate_rate_source(deps.api, config.rate_source)?,
+        reference_rate: None,
+        sell: config.sell,
+        buy: config.buy,
+        swap_fee_bps: config.swap_fee_bps,
+        circuit_breaker: config.circuit_breaker,
+        volume_limits: config.volume_limits,
+        rate_delay_seconds: config.rate_delay_seconds,
+        keeper_tip_bps: config.keeper_tip_bps,
+        enabled: true,
+        paused: false,
+    };
+    PAIRS.save(deps.storage, pair.base_token.as_str(), &pair)?;
+    for asset in [&pair.base_token, &pair.quote_token] {
+        ASSETS.save(deps.storage, asset.as_str(), asset)?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "add_pair")
+        .add_attribute("base_token", pair.base_token.as_str())
+        .add_attribute("quote_token", pair.quote_token.as_str()))
+}
+
+pub fn execute_update_pair(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    base_token: String,
+    update: PairUpdate,
+) -> Result<Response, ContractError> {
+    OWNERSHIP.assert_admin(deps.storage, &info.sender)?;
+    let mut pair = load_pair(deps.storage, &base_token)?;
+
+    // A new rate source or a shorter delay would let the rate change sooner than announced, so
+    // on a timelocked pair both wait out the current delay
+    let mut pending = PendingPairUpdate {
+        rate_source: None,
+        rate_delay_seconds: None,
+        effective_at: env.block.time.plus_seconds(pair.rate_delay_seconds),
+    };
+    if let Some(rate_source) = update.rate_source {
+        let rate_source = validate_rate_source(deps.api, rate_source)?;
+        if pair.rate_delay_seconds > 0 {
+            pending.rate_source = Some(rate_source);
+        } else {
+            pair.rate_source = rate_source;
+            pair.reference_rate = None;
+        }
+    }
+    if let Some(params) = update.sell {
+        pair.sell = params;
+    }
+    if let Some(params) = update.buy {
+        pair.buy = params;
+    }
+    if let Some(swap_fee_bps) = update.swap_fee_bps {
+        pair.swap_fee_bps = swap_fee_bps;
+    }
+    if let Some(circuit_breaker) = update.circuit_breaker {
+        pair.circuit_breaker = circuit_breaker;
+    }
+    if let Some(volume_limits) = update.volume_limits {
+        validate_volume_limits(&volume_limits)?;
+        pair.volume_limits = volume_limits;
+    }
+    if let Some(rate_delay_seconds) = update.rate_delay_seconds {
+        if rate_delay_seconds < pair.rate_delay_seconds {
+            pending.rate_delay_seconds = Some(rate_delay_seconds);
+        } else {
+            pair.rate_delay_seconds = rate_delay_seconds;
+        }
+    }
+    if let Some(keeper_tip_bps) = update.keeper_tip_bps {
+        validate_keeper_tip(keeper_tip_bps)?;
+        pair.keeper_tip_bps = keeper_tip_bps;
+    }
+    if let Some(enabled) = update.enabled {
+        pair.enabled = enabled;
+    }
+    // Checked against the final fee, whichever of the two changed
+    validate_swap_params(&pair.sell, pair.swap_fee_bps)?;
+    validate_swap_params(&pair.buy, pair.swap_fee_bps)?;
+    PAIRS.save(deps.storage, pair.base_token.as_str(), &pair)?;
+
+    let mut response = Response::new()
+        .add_attribute("action", "update_pair")
+        .add_attribute("base_token", pair.base_token.as_str())
+        .add_attribute("enabled", pair.enabled.to_string());
+    // Replaces whatever update was still pending
+    if pending.rate_source.is_some() || pending.rate_delay_seconds.is_some() {
+        PENDING_PAIR_UPDATES.save(deps.storage, pair.base_token.as_str(), &pending)?;
+        response = response.add_attribute("pending_until", pending.effective_at.to_string());
+    }
+    Ok(response)
+}
+
+pub fn execute_apply_pair_update(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    base_token: String,
+) -> Result<Response, ContractError> {
+    OWNERSHIP.assert_admin(deps.storage, &info.sender)?;
+    let mut pair = load_pair(deps.storage, &base_token)?;
+    let key = pair.base_token.as_str().to_string();
+    let pending = PENDING_PAIR_UPDATES
+        .may_load(deps.storage, &key)?
+        .ok_or_else(|| ContractError::NoPendingPairUpdate { token: key.clone() })?;
+    if pending.effective_at > env.block.time {
+        return Err(ContractError::PairUpdateNotDue {
+            effective_at: pending.effective_at,
+        });
+    }
+
+    if let Some(rate_source) = pending.rate_source {
+        pair.rate_source = rate_source;
+        pair.reference_rate = None;
+    }
+    if let Some(rate_delay_seconds) = pending.rate_delay_seconds {
+        pair.rate_delay_seconds = rate_delay_seconds;
+    }
+    PAIRS.save(deps.storage, &key, &pair)?;
+    PENDING_PAIR_UPDATES.remove(deps.storage, &key);
+
+    Ok(Response::new()
+        .add_attribute("action", "apply_pair_update")
+        .add_attribute("base_token", key))
+}
+
+pub fn execute_cancel_pair_update(
+    deps: DepsMut,
+    info: MessageInfo,
+    base_token: String,
+) -> Result<Response, ContractError> {
+    PAUSE.assert_guardian(deps.storage, &info.sender)?;
+    let pair = load_pair(deps.storage, &base_token)?;
+    let key = pair.base_token.as_str();
+    if !PENDING_PAIR_UPDATES.has(deps.storage, key) {
+        return Err(ContractError::NoPendingPairUpdate {
+            token: key.to_string(),
+        });
+    }
+    PENDING_PAIR_UPDATES.remove(deps.storage, key);
+
+    Ok(Response::new()
+        .add_attribute("action", "cancel_pair_update")
+        .add_attribute("base_token", key))
+}
+
+pub fn execute_disable_pair(
+    deps: DepsMut,
+    info: MessageInfo,
+    base_token: String,
+) -> Result<Response, ContractError> {
+    OWNERSHIP.assert_admin(deps.storage, &info.sender)?;
+
+    let mut pair = load_pair(deps.storage, &base_token)?;
+    pair.enabled = false;
+    PAIRS.save(deps.storage, pair.base_token.as_str(), &pair)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "disable_pair")
+        .add_attribute("base_token", pair.base_token.as_str()))
+}
+
+pub fn execute_set_exchange_rate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    base_token: String,
+    rate: Decimal,
+) -> Result<Response, ContractError> {
+    OWNERSHIP.assert_role(deps.storage, &info.sender, Role::RateSetter)?;
+    let mut pair = load_pair(deps.storage, &base_token)?;
+    if pair.rate_delay_seconds > 0 {
+        return Err(ContractError::RateTimelocked {
+            delay_seconds: pair.rate_delay_seconds,
+        });
+    }
+    activate_scheduled_rate(deps.storage, &env, &mut pair)?;
+    check_rate_update(deps.storage, &env, &pair, rate)?;
+
+    update_exchange_rate(deps, env, pair, rate, "set_exchange_rate")
+}
+
+pub fn execute_propose_rate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    base_token: String,
+    rate: Decimal,
+    effective_at: Timestamp,
+) -> Result<Response, ContractError> {
+    OWNERSHIP.assert_role(deps.storage, &info.sender, Role::RateSetter)?;
+    let mut pair = load_pair(deps.storage, &base_token)?;
+    if effective_at < env.block.time.plus_seconds(pair.rate_delay_seconds) {
+        return Err(ContractError::RateTimelocked {
+            delay_seconds: pair.rate_delay_seconds,
+        });
+    }
+    // A proposal that already took effect is the baseline for this one
+    activate_scheduled_rate(deps.storage, &env, &mut pair)?;
+    check_rate_update(deps.storage, &env, &pair, rate)?;
+
+    schedule_rate(
+        deps.storage,
+        &env,
+        &mut pair,
+        &ScheduledRate { rate, effective_at },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "propose_rate")
+        .add_attribute("base_token", pair.base_token.as_str())
+        .add_attribute("rate", rate.to_string())
+        .add_attribute("effective_at", effective_at.to_string()))
+}
+
+pub fn execute_cancel_rate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    base_token: String,
+) -> Result<Response, ContractError> {
+    PAUSE.assert_guardian(deps.storage, &info.sender)?;
+    let mut pair = load_pair(deps.storage, &base_token)?;
+    // Too late once it has taken effect
+    if !cancel_scheduled_rate(deps.storage, &env, &mut pair)? {
+        return Err(ContractError::NoPendingRate {
+            token: pair.base_token.as_str().to_string(),
+        });
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "cancel_rate")
+        .add_attribute("base_token", pair.base_token.as_str()))
+}
+
+pub fn execute_set_paused(
+    deps: DepsMut,
+    info: MessageInfo,
+    base_token: Option<String>,
+    paused: bool,
+) -> Result<Response, ContractError> {
+    let action = if paused { "pause" } else { "unpause" };
+    match base_token {
+        Some(base_token) => {
+            OWNERSHIP.assert_role(deps.storage, &info.sender, Role::Pauser)?;
+            let mut pair = load_pair(deps.storage, &base_token)?;
+            pair.paused = paused;
+            // After an auto-pause, the next swap takes the then-current price as its baseline
+            pair.reference_rate = None;
+            PAIRS.save(deps.storage, pair.base_token.as_str(), &pair)?;
+
+            Ok(Response::new()
+                .add_attribute("action", action)
+                .add_attribute("base_token", pair.base_token.as_str()))
+        }
+        // Every pair's swaps, the same pause as `EmergencyPause`, so it is the guardian's alone
+        None => {
+            PAUSE.set_paused(deps.storage, &info.sender, Some(Operation::Swap), paused)?;
+
+            Ok(Response::new().add_attribute("action", action))
+        }
+    }
+}
+
+pub fn execute_set_market_maker(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+    allowed: bool,
+) -> Result<Response, ContractError> {
+    OWNERSHIP.assert_admin(deps.storage, &info.sender)?;
+
+    let market_maker = deps.api.addr_validate(&address)?;
+    if allowed {
+        MARKET_MAKERS.save(deps.storage, &market_maker, &Empty {})?;
+    } else {
+        MARKET_MAKERS.remove(deps.storage, &market_maker);
+    }
+
+    let action = if allowed {
+        "add_market_maker"
+    } else {
+        "remove_market_maker"
+    };
+    Ok(Response::new()
+        .add_attribute("action", action)
+        .add_attribute("market_maker", market_maker))
+}
+
+pub fn execute_withdraw_fees(
+    deps: DepsMut,
+    info: MessageInfo,
+    to: String,
+) -> Result<Response, ContractError> {
+    OWNERSHIP.assert_role(deps.storage, &info.sender, Role::Treasury)?;
+    let to = deps.api.addr_validate(&to)?;
+
+    let fees = COLLECTED_FEES
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    let mut response = Response::new()
+        .add_attribute("action", "withdraw_fees")
+        .add_attribute("to", &to);
+    for (token, amount) in fees {
+        COLLECTED_FEES.remove(deps.storage, &token);
+        if amount.is_zero() {
+            continue;
+        }
+        response = response
+            .add_message(transfer_msg(
+                &load_asset(deps.storage, &token)?,
+                to.to_string(),
+                amount,
+            )?)
+            .add_attribute("token", token)
+            .add_attribute("amount", amount);
+    }
+
+    Ok(response)
+}
+
+pub fn execute_add_feeder(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    OWNERSHIP.assert_admin(deps.storage, &info.sender)?;
+
+    let feeder = deps.api.addr_validate(&address)?;
+    FEEDERS.save(deps.storage, &feeder, &Empty {})?;
+
+    Ok(Response::new()
+        .add_attribute("action", "add_feeder")
+        .add_attribute("feeder", feeder))
+}
+
+pub fn execute_remove_feeder(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    OWNERSHIP.assert_admin(deps.storage, &info.sender)?;
+
+    let feeder = deps.api.addr_validate(&address)?;
+    FEEDERS.remove(deps.storage, &feeder);
+
+    Ok(Response::new()
+        .add_attribute("action", "remove_feeder")
+        .add_attribute("feeder", feeder))
+}
+
+pub fn execute_set_auditor(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+    allowed: bool,
+) -> Result<Response, ContractError> {
+    OWNERSHIP.assert_admin(deps.storage, &info.sender)?;
+
+    let auditor = deps.api.addr_validate(&address)?;
+    if allowed {
+        AUDITORS.save(deps.storage, &auditor, &Empty {})?;
+    } else {
+        AUDITORS.remove(deps.storage, &auditor);
+    }
+
+    let action = if allowed {
+        "add_auditor"
+    } else {
+        "remove_auditor"
+    };
+    Ok(Response::new()
+        .add_attribute("action", action)
+        .add_attribute("auditor", auditor))
+}
+
+pub fn execute_set_reserve_backing(
+    deps: DepsMut,
+    info: MessageInfo,
+    base_token: String,
+    backing: Option<ReserveBacking>,
+) -> Result<Response, ContractError> {
+    OWNERSHIP.assert_admin(deps.storage, &info.sender)?;
+    let pair = load_pair(deps.storage, &base_token)?;
+
+    let key = pair.base_token.as_str();
+    match &backing {
+        Some(backing) => RESERVE_BACKING.save(deps.storage, key, backing)?,
+        None => RESERVE_BACKING.remove(deps.storage, key),
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "set_reserve_backing")
+        .add_attribute("base_token", key)
+        .add_attribute("backed", backing.is_some().to_string()))
+}
+
+pub fn execute_post_attestation(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    base_token: String,
+    ounces: Decimal,
+    report_hash: String,
+    reported_at: Timestamp,
+) -> Result<Response, ContractError> {
+    if !AUDITORS.has(deps.storage, &info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+    let pair = load_pair(deps.storage, &base_token)?;
+    let key = pair.base_token.as_str();
+
+    if report_hash.is_empty() {
+        return Err(ContractError::InvalidAttestation {
+            reason: "report hash must not be empty".to_string(),
+        });
+    }
+    if reported_at > env.block.time {
+        return Err(ContractError::InvalidAttestation {
+            reason: "report is dated in the future".to_string(),
+        });
+    }
+    if let Some(latest) = ATTESTATIONS.may_load(deps.storage, key)? {
+        if reported_at < latest.reported_at {
+            return Err(ContractError::InvalidAttestation {
+                reason: "report is older than the latest attestation".to_string(),
+            });
+        }
+    }
+
+    let attestation = Attestation {
+        ounces,
+        auditor: info.sender,
+        report_hash,
+        reported_at,
+        posted_at: env.block.time,
+    };
+    ATTESTATIONS.save(deps.storage, key, &attestation)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "post_attestation")
+        .add_attribute("base_token", key)
+        .add_attribute("auditor", attestation.auditor)
+        .add_attribute("ounces", ounces.to_string())
+        .add_attribute("report_hash", attestation.report_hash))
+}
+
+pub fn execute_submit_price(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    base_token: String,
+    price: Decimal,
+) -> Result<Response, ContractError> {
+    if !FEEDERS.has(deps.storage, &info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+    let pair = load_pair(deps.storage, &base_token)?;
+    if !matches!(pair.rate_source, RateSource::Feeders { .. }) {
+        return Err(ContractError::InvalidPriceSubmission {
+            reason: format!("{} does not take feeder prices", pair.base_token.as_str()),
+        });
+    }
+    if price.is_zero() {
+        return Err(ContractError::InvalidPriceSubmission {
+            reason: "price must be positive".to_string(),
+        });
+    }
+
+    let submission = PriceSubmission {
+        price,
+        submitted_at: env.block.time,
+    };
+    SUBMISSIONS.save(
+        deps.storage,
+        (pair.base_token.as_str(), &info.sender),
+        &submission,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "submit_price")
+        .add_attribute("base_token", pair.base_token.as_str())
+        .add_attribute("feeder", info.sender)
+        .add_attribute("price", price.to_string()))
+}
+
+fn update_exchange_rate(
+    deps: DepsMut,
+    env: Env,
+    mut pair: Pair,
+    rate: Decimal,
+    action: &str,
+) -> Result<Response, ContractError> {
+    // A direct change supersedes any announced one
+    cancel_scheduled_rate(deps.storage, &env, &mut pair)?;
+    pair.rate = rate;
+    pair.rate_updated_at = Some(env.block.time);
+    PAIRS.save(deps.storage, pair.base_token.as_str(), &pair)?;
+    record_rate(deps.storage, pair.base_token.as_str(), env.block.time, rate)?;
+
+    let response = Response::new()
+        .add_attribute("action", action)
+        .add_attribute("base_token", pair.base_token.as_str())
+        .add_attribute("rate", rate.to_string());
+
+    // Orders the new rate reaches fill right away, one batch's worth; `ExecuteOrders` takes the rest
+    let tradable = !PAUSE.is_paused(deps.storage, Operation::Swap)? && pair.enabled && !pair.paused;
+    if tradable && matches!(pair.rate_source, RateSource::Fixed {}) {
+        return fill_orders(
+            deps.storage,
+            &env,
+            &pair,
+            rate,
+            DEFAULT_LIMIT as usize,
+            response,
+        );
+    }
+    Ok(response)
+}
+
+pub fn execute_receive(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    cw20_msg: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    let receive_msg: ReceiveMsg = from_json(&cw20_msg.msg)?;
+    let operation = match receive_msg {
+        ReceiveMsg::FundReserve {} => Operation::Deposit,
+        _ => Operation::Swap,
+    };
+    PAUSE.assert_not_paused(deps.storage, operation)?;
+    let sender = deps.api.addr_validate(&cw20_msg.sender)?;
+    let offer = Asset {
+        info: AssetInfo::Cw20 {
+            addr: info.sender.to_string(),
+        },
+        amount: cw20_msg.amount,
+    };
+    match receive_msg {
+        ReceiveMsg::Swap {
+            ask_token,
+            min_output,
+            deadline,
+        } => execute_swap(deps, env, sender, offer, ask_token, min_output, deadline),
+        ReceiveMsg::PlaceLimitOrder {
+            min_rate,
+            expires_at,
+        } => execute_place_limit_order(deps, env, sender, offer, min_rate, expires_at),
+        ReceiveMsg::CreateDcaPlan {
+            base_token,
+            per_swap_amount,
+            interval_seconds,
+            max_swaps,
+        } => {
+            let plan = DcaPlanParams {
+                base_token,
+                per_swap_amount,
+                interval_seconds,
+                max_swaps,
+            };
+            execute_create_dca_plan(deps, env, sender, offer, plan)
+        }
+        ReceiveMsg::FundReserve {} => execute_fund_reserve(deps, sender, vec![offer]),
+    }
+}
+
+pub fn execute_fund_reserve(
+    deps: DepsMut,
+    sender: Addr,
+    deposits: Vec<Asset>,
+) -> Result<Response, ContractError> {
+    OWNERSHIP.assert_admin(deps.storage, &sender)?;
+    if deposits.is_empty() {
+        return Err(ContractError::InsufficientFunds {
+            expected: "at least one coin".to_string(),
+        });
+    }
+
+    let mut response = Response::new().add_attribute("action", "fund_reserve");
+    for deposit in deposits {
+        // Only assets traded by some pair can back swaps
+        let token = deposit.info.as_str();
+        if ASSETS.may_load(deps.storage, token)?.as_ref() != Some(&deposit.info) {
+            return Err(ContractError::InvalidToken {
+                token: token.to_string(),
+            });
+        }
+
+        add_reserve(deps.storage, token, deposit.amount)?;
+        response = response
+            .add_attribute("token", token)
+            .add_attribute("amount", deposit.amount);
+    }
+
+    Ok(response)
+}
+
+pub fn execute_withdraw_reserve(
+    deps: DepsMut,
+    info: MessageInfo,
+    token: String,
+    amount: Uint128,
+    to: String,
+) -> Result<Response, ContractError> {
+    OWNERSHIP.assert_admin(deps.storage, &info.sender)?;
+    let to = deps.api.addr_validate(&to)?;
+    let asset = load_asset(deps.storage, &token)?;
+
+    take_reserve(deps.storage, &token, amount)?;
+
+    Ok(Response::new()
+        .add_message(transfer_msg(&asset, to.to_string(), amount)?)
+        .add_attribute("action", "withdraw_reserve")
+        .add_attribute("token", token)
+        .add_attribute("amount", amount)
+        .add_attribute("to", to))
+}
+
+fn execute_swap(
+    mut deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    offer: Asset,
+    ask_token: Option<String>,
+    min_output: Option<Uint128>,
+    deadline: Option<Timestamp>,
+) -> Result<Response, ContractError> {
+    // The offered asset picks the pair: a base token sells it, a quote token buys `ask_token`
+    let (mut pair, direction) = route(
+        deps.storage,
+        Some(offer.info.as_str()),
+        ask_token.as_deref(),
+    )?;
+    // A denom and a CW20 address never collide in practice, but the kind must match too
+    if *direction.offer_token(&pair) != offer.info {
+        return Err(ContractError::InvalidToken {
+            token: offer.info.as_str().to_string(),
+        });
+    }
+    if !pair.enabled {
+        return Err(ContractError::PairDisabled {
+            token: pair.base_token.as_str().to_string(),
+        });
+    }
+    if pair.paused {
+        return Err(ContractError::Paused {});
+    }
+    // Only as much of the base token as the vault demonstrably holds can be bought
+    if direction == Direction::Buy {
+        let backing = RESERVE_BACKING.may_load(deps.storage, pair.base_token.as_str())?;
+        if let Some(backing) = backing {
+            check_backing(deps.as_ref(), &env, &pair, &backing)?;
+        }
+    }
+    activate_scheduled_rate(deps.storage, &env, &mut pair)?;
+    if let Some(deadline) = deadline {
+        if env.block.time > deadline {
+            return Err(ContractError::Expired {});
+        }
+    }
+
+    let rate = observe_rate(deps.branch(), &env, &mut pair)?;
+    if pair.paused {
+        // The circuit breaker tripped. Erroring would roll the pause back, so refund the offer
+        // and succeed instead.
+        return Ok(Response::new()
+            .add_message(transfer_msg(&offer.info, sender.to_string(), offer.amount)?)
+            .add_attribute("action", "circuit_breaker_tripped")
+            .add_attribute("base_token", pair.base_token.as_str())
+            .add_attribute("rate", rate.to_string()));
+    }
+
+    let (send_msg, trade, outcome) =
+        settle_swap(deps.storage, &env, &pair, direction, sender, offer, rate)?;
+    ensure_min_output(outcome.return_amount, min_output)?;
+
+    Ok(Response::new()
+        .add_message(send_msg)
+        .add_event(swap_event(&trade))
+        .add_attribute("action", "swap")
+        .add_attribute("trade_id", trade.id.to_string())
+        .add_attribute("base_token", pair.base_token.as_str())
+        .add_attribute("direction", direction.as_str())
+        .add_attribute("rate", rate.to_string())
+        .add_attribute("offer_amount", trade.offer.amount.to_string())
+        .add_attribute("return_amount", outcome.return_amount.to_string())
+        .add_attribute("spread_amount", outcome.spread_amount.to_string())
+        .add_attribute("fee_amount", outcome.fee_amount.to_string())
+        .add_attribute(
+            "protocol_fee_amount",
+            outcome.protocol_fee_amount.to_string(),
+        )
+        .add_attribute("fee_token", trade.fee.info.as_str()))
+}
+
+/// Current rate of `pair`. Oracle and feeder rates enter the history as swaps and order fills
+/// observe them, and one that jumped past the circuit breaker threshold pauses the pair.
+fn observe_rate(deps: DepsMut, env: &Env, pair: &mut Pair) -> Result<Decimal, ContractError> {
+    let rate = current_rate(deps.as_ref(), env, pair)?;
+    if matches!(pair.rate_source, RateSource::Fixed {}) {
+        return Ok(rate);
+    }
+
+    if should_auto_pause(pair, rate)? {
+        pair.paused = true;
+    } else {
+        if pair.reference_rate != Some(rate) {
+            record_rate(deps.storage, pair.base_token.as_str(), env.block.time, rate)?;
+        }
+        pair.reference_rate = Some(rate);
+    }
+    PAIRS.save(deps.storage, pair.base_token.as_str(), pair)?;
+    Ok(rate)
+}
+
+/// Books a swap of `offer` on `pair` at `rate`: the payout and fees leave the reserve, the
+/// offer joins it and a receipt is stored. Returns the payout message, the receipt and the
+/// breakdown of the output.
+fn settle_swap(
+    storage: &mut dyn Storage,
+    env: &Env,
+    pair: &Pair,
+    direction: Direction,
+    user: Addr,
+    offer: Asset,
+    rate: Decimal,
+) -> Result<(CosmosMsg, Trade, SwapOutcome), ContractError> {
+    let outcome = simulate(pair, direction, rate, offer.amount)?;
+
+    let base_amount = match direction {
+        Direction::Sell => offer.amount,
+        Direction::Buy => outcome.return_amount,
+    };
+    record_volume(storage, env, pair, &user, base_amount)?;
+
+    // The direction's fee and the protocol fee are both kept as revenue; the spread is not
+    let ask_token = direction.ask_token(pair);
+    let collected = outcome.fee_amount + outcome.protocol_fee_amount;
+
+    // The payout and the fees both leave the reserve, the offer joins it
+    take_reserve(
+        storage,
+        ask_token.as_str(),
+        outcome.return_amount.checked_add(collected)?,
+    )?;
+    add_reserve(storage, offer.info.as_str(), offer.amount)?;
+    COLLECTED_FEES.update(storage, ask_token.as_str(), |fees| -> StdResult<_> {
+        Ok(fees.unwrap_or_default().checked_add(collected)?)
+    })?;
+
+    let send_msg = transfer_msg(ask_token, user.to_string(), outcome.return_amount)?;
+    let trade = record_trade(
+        storage,
+        Trade {
+            id: 0,
+            user,
+            base_token: pair.base_token.as_str().to_string(),
+            offer,
+            ask: Asset {
+                info: ask_token.clone(),
+                amount: outcome.return_amount,
+            },
+            rate,
+            fee: Asset {
+                info: ask_token.clone(),
+                amount: collected,
+            },
+            timestamp: env.block.time,
+        },
+    )?;
+
+    Ok((send_msg, trade, outcome))
+}
+
+/// Whether [`settle_swap`] would go through, checked without writing anything so that an order
+/// the reserve or the volume caps cannot take yet does not fail the whole batch.
+fn can_settle(
+    storage: &dyn Storage,
+    env: &Env,
+    pair: &Pair,
+    direction: Direction,
+    user: &Addr,
+    offer: &Asset,
+    rate: Decimal,
+) -> Result<bool, ContractError> {
+    let outcome = simulate(pair, direction, rate, offer.amount)?;
+
+    let ask_token = direction.ask_token(pair);
+    let needed = outcome
+        .return_amount
+        .checked_add(outcome.fee_amount + outcome.protocol_fee_amount)?;
+    if needed
+        > RESERVES
+            .may_load(storage, ask_token.as_str())?
+            .unwrap_or_default()
+    {
+        return Ok(false);
+    }
+
+    let base_amount = match direction {
+        Direction::Sell => offer.amount,
+        Direction::Buy => outcome.return_amount,
+    };
+    let allowance = remaining_allowance(storage, env, pair, user)?;
+    Ok([allowance.daily, allowance.epoch]
+        .into_iter()
+        .flatten()
+        .all(|remaining| base_amount <= remaining))
+}
+
+pub fn execute_place_limit_order(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    offer: Asset,
+    min_rate: Decimal,
+    expires_at: Option<Timestamp>,
+) -> Result<Response, ContractError> {
+    // Orders only sell a pair's base token
+    let (pair, _) = route(deps.storage, Some(offer.info.as_str()), None)?;
+    if pair.base_token != offer.info {
+        return Err(ContractError::InvalidToken {
+            token: offer.info.as_str().to_string(),
+        });
+    }
+    if !pair.enabled {
+        return Err(ContractError::PairDisabled {
+            token: pair.base_token.as_str().to_string(),
+        });
+    }
+    if min_rate.is_zero() {
+        return Err(ContractError::InvalidOrder {
+            reason: "min_rate must be positive".to_string(),
+        });
+    }
+    if expires_at.is_some_and(|expires_at| expires_at <= env.block.time) {
+        return Err(ContractError::InvalidOrder {
+            reason: "expires_at must be in the future".to_string(),
+        });
+    }
+    // Orders fill in one swap, so one above a volume cap could never fill
+    if !MARKET_MAKERS.has(deps.storage, &sender) {
+        let limits = &pair.volume_limits;
+        if let Some(cap) = [limits.per_address_daily, limits.global_per_epoch]
+            .into_iter()
+            .flatten()
+            .min()
+        {
+            if offer.amount > cap {
+                return Err(ContractError::InvalidOrder {
+                    reason: format!("offer exceeds the volume limit of {cap}"),
+                });
+            }
+        }
+    }
+
+    let order = place_order(
+        deps.storage,
+        LimitOrder {
+            id: 0,
+            owner: sender,
+            base_token: pair.base_token.as_str().to_string(),
+            offer,
+            min_rate,
+            expires_at,
+            created_at: env.block.time,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "place_limit_order")
+        .add_attribute("order_id", order.id.to_string())
+        .add_attribute("owner", order.owner)
+        .add_attribute("base_token", order.base_token)
+        .add_attribute("offer_amount", order.offer.amount.to_string())
+        .add_attribute("min_rate", min_rate.to_string()))
+}
+
+/// Schedule of a new DCA plan, as sent in `CreateDcaPlan`.
+pub struct DcaPlanParams {
+    pub base_token: String,
+    pub per_swap_amount: Uint128,
+    pub interval_seconds: u64,
+    pub max_swaps: u32,
+}
+
+pub fn execute_create_dca_plan(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    deposit: Asset,
+    params: DcaPlanParams,
+) -> Result<Response, ContractError> {
+    // Plans buy the base token with the pair's quote token
+    let (pair, direction) = route(
+        deps.storage,
+        Some(deposit.info.as_str()),
+        Some(&params.base_token),
+    )?;
+    if direction != Direction::Buy || pair.quote_token != deposit.info {
+        return Err(ContractError::InvalidToken {
+            token: deposit.info.as_str().to_string(),
+        });
+    }
+    if !pair.enabled {
+        return Err(ContractError::PairDisabled {
+            token: pair.base_token.as_str().to_string(),
+        });
+    }
+    if params.per_swap_amount.is_zero() || params.interval_seconds == 0 || params.max_swaps == 0 {
+        return Err(ContractError::InvalidDcaPlan {
+            reason: "per_swap_amount, interval_seconds and max_swaps must be positive".to_string(),
+        });
+    }
+    let total = params
+        .per_swap_amount
+        .checked_mul(Uint128::from(params.max_swaps))?;
+    if deposit.amount != total {
+        return Err(ContractError::InsufficientFunds {
+            expected: format!("{} {}", total, deposit.info.as_str()),
+        });
+    }
+
+    let plan = create_plan(
+        deps.storage,
+        DcaPlan {
+            id: 0,
+            owner: sender,
+            base_token: pair.base_token.as_str().to_string(),
+            balance: deposit,
+            per_swap_amount: params.per_swap_amount,
+            interval_seconds: params.interval_seconds,
+            max_swaps: params.max_swaps,
+            swaps_done: 0,
+            next_swap_at: env.block.time,
+            paused: false,
+            created_at: env.block.time,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "create_dca_plan")
+        .add_attribute("plan_id", plan.id.to_string())
+        .add_attribute("owner", plan.owner)
+        .add_attribute("base_token", plan.base_token)
+        .add_attribute("deposit", plan.balance.amount.to_string()))
+}
+
+pub fn execute_execute_due(
+    mut deps: DepsMut,
+    env: Env,
+    keeper: Addr,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let plans = due_plans(deps.storage, env.block.time, limit)?;
+
+    let mut response = Response::new().add_attribute("action", "execute_due");
+    let (mut executed, mut skipped) = (0u32, 0u32);
+    for mut plan in plans {
+        remove_plan(deps.storage, &plan);
+        match dca_swap(deps.branch(), &env, &plan)? {
+            Some((send_msg, trade, tip)) => {
+                plan.balance.amount = plan.balance.amount.checked_sub(plan.per_swap_amount)?;
+                plan.swaps_done += 1;
+                response = response.add_message(send_msg).add_event(
+                    swap_event(&trade).add_attribute("dca_plan_id", plan.id.to_string()),
+                );
+                if !tip.is_zero() {
+                    let tip_msg = transfer_msg(&plan.balance.info, keeper.to_string(), tip)?;
+                    response = response.add_message(tip_msg);
+                }
+                executed += 1;
+            }
+            None => skipped += 1,
+        }
+
+        // A finished plan has spent its whole deposit
+        if plan.swaps_done < plan.max_swaps {
+            plan.next_swap_at = env.block.time.plus_seconds(plan.interval_seconds);
+            save_plan(deps.storage, &plan)?;
+        }
+    }
+
+    Ok(response
+        .add_attribute("keeper", keeper)
+        .add_attribute("swaps_executed", executed.to_string())
+        .add_attribute("swaps_skipped", skipped.to_string()))
+}
+
+/// Buys the plan's base token with one installment of its deposit, minus the keeper tip.
+/// Returns `None` when the pair cannot take the swap right now, leaving the deposit untouched.
+fn dca_swap(
+    mut deps: DepsMut,
+    env: &Env,
+    plan: &DcaPlan,
+) -> Result<Option<(CosmosMsg, Trade, Uint128)>, ContractError> {
+    let mut pair = load_pair(deps.storage, &plan.base_token)?;
+    if !pair.enabled || pair.paused {
+        return Ok(None);
+    }
+    activate_scheduled_rate(deps.storage, env, &mut pair)?;
+    if let Some(backing) = RESERVE_BACKING.may_load(deps.storage, pair.base_token.as_str())? {
+        if check_backing(deps.as_ref(), env, &pair, &backing).is_err() {
+            return Ok(None);
+        }
+    }
+    let Ok(rate) = observe_rate(deps.branch(), env, &mut pair) else {
+        return Ok(None);
+    };
+    if pair.paused || rate.is_zero() {
+        return Ok(None);
+    }
+
+    let tip = bps_of_amount(plan.per_swap_amount, pair.keeper_tip_bps);
+    let offer = Asset {
+        info: plan.balance.info.clone(),
+        amount: plan.per_swap_amount - tip,
+    };
+    let direction = Direction::Buy;
+    if !can_settle(
+        deps.storage,
+        env,
+        &pair,
+        direction,
+        &plan.owner,
+        &offer,
+        rate,
+    )? {
+        return Ok(None);
+    }
+
+    let (send_msg, trade, _) = settle_swap(
+        deps.storage,
+        env,
+        &pair,
+        direction,
+        plan.owner.clone(),
+        offer,
+        rate,
+    )?;
+    Ok(Some((send_msg, trade, tip)))
+}
+
+pub fn execute_set_dca_plan_paused(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+    paused: bool,
+) -> Result<Response, ContractError> {
+    let mut plan = load_dca_plan(deps.storage, id)?;
+    if plan.owner != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    remove_plan(deps.storage, &plan);
+    plan.paused = paused;
+    // Swaps missed while paused are not made up
+    plan.next_swap_at = plan.next_swap_at.max(env.block.time);
+    save_plan(deps.storage, &plan)?;
+
+    let action = if paused {
+        "pause_dca_plan"
+    } else {
+        "resume_dca_plan"
+    };
+    Ok(Response::new()
+        .add_attribute("action", action)
+        .add_attribute("plan_id", id.to_string()))
+}
+
+pub fn execute_cancel_dca_plan(
+    deps: DepsMut,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let plan = load_dca_plan(deps.storage, id)?;
+    if plan.owner != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    remove_plan(deps.storage, &plan);
+    let refund_msg = transfer_msg(
+        &plan.balance.info,
+        plan.owner.to_string(),
+        plan.balance.amount,
+    )?;
+
+    Ok(Response::new()
+        .add_message(refund_msg)
+        .add_attribute("action", "cancel_dca_plan")
+        .add_attribute("plan_id", id.to_string())
+        .add_attribute("refund_amount", plan.balance.amount.to_string()))
+}
+
+fn load_dca_plan(storage: &dyn Storage, id: u64) -> Result<DcaPlan, ContractError> {
+    DCA_PLANS
+        .may_load(storage, id)?
+        .ok_or(ContractError::DcaPlanNotFound { id })
+}
+
+pub fn execute_cancel_order(
+    deps: DepsMut,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let order = ORDERS
+        .may_load(deps.storage, id)?
+        .ok_or(ContractError::OrderNotFound { id })?;
+    if order.owner != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    remove_order(deps.storage, &order);
+    let refund_msg = transfer_msg(
+        &order.offer.info,
+        order.owner.to_string(),
+        order.offer.amount,
+    )?;
+
+    Ok(Response::new()
+        .add_message(refund_msg)
+        .add_attribute("action", "cancel_order")
+        .add_attribute("order_id", id.to_string())
+        .add_attribute("refund_amount", order.offer.amount.to_string()))
+}
+
+pub fn execute_execute_orders(
+    mut deps: DepsMut,
+    env: Env,
+    base_token: String,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let mut pair = load_pair(deps.storage, &base_token)?;
+    if !pair.enabled {
+        return Err(ContractError::PairDisabled {
+            token: pair.base_token.as_str().to_string(),
+        });
+    }
+    if pair.paused {
+        return Err(ContractError::Paused {});
+    }
+    activate_scheduled_rate(deps.storage, &env, &mut pair)?;
+
+    let rate = observe_rate(deps.branch(), &env, &mut pair)?;
+    if pair.paused {
+        return Ok(Response::new()
+            .add_attribute("action", "circuit_breaker_tripped")
+            .add_attribute("base_token", pair.base_token.as_str())
+            .add_attribute("rate", rate.to_string()));
+    }
+
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let response = Response::new()
+        .add_attribute("action", "execute_orders")
+        .add_attribute("base_token", pair.base_token.as_str())
+        .add_attribute("rate", rate.to_string());
+    fill_orders(deps.storage, &env, &pair, rate, limit, response)
+}
+
+/// Looks at up to `limit` of the pair's orders that `rate` meets, fills them and refunds the
+/// expired ones among them. Orders the reserve or the volume caps cannot take yet stay in the
+/// book, and the next call moves on past them.
+fn fill_orders(
+    storage: &mut dyn Storage,
+    env: &Env,
+    pair: &Pair,
+    rate: Decimal,
+    limit: usize,
+    mut response: Response,
+) -> Result<Response, ContractError> {
+    let orders = next_crank_orders(storage, pair.base_token.as_str(), rate, limit)?;
+
+    let (mut filled, mut expired) = (0u32, 0u32);
+    for order in orders {
+        if order
+            .expires_at
+            .is_some_and(|expires_at| env.block.time >= expires_at)
+        {
+            remove_order(storage, &order);
+            response = response.add_message(transfer_msg(
+                &order.offer.info,
+                order.owner.to_string(),
+                order.offer.amount,
+            )?);
+            expired += 1;
+            continue;
+        }
+        let direction = Direction::Sell;
+        if !can_settle(
+            storage,
+            env,
+            pair,
+            direction,
+            &order.owner,
+            &order.offer,
+            rate,
+        )? {
+            continue;
+        }
+
+        remove_order(storage, &order);
+        let (send_msg, trade, _) = settle_swap(
+            storage,
+            env,
+            pair,
+            direction,
+            order.owner,
+            order.offer,
+            rate,
+        )?;
+        response = response
+            .add_message(send_msg)
+            .add_event(swap_event(&trade).add_attribute("order_id", order.id.to_string()));
+        filled += 1;
+    }
+
+    Ok(response
+        .add_attribute("orders_filled", filled.to_string())
+        .add_attribute("orders_expired", expired.to_string()))
+}
+
+fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
+    Ok(ConfigResponse {
+        paused: PAUSE.is_paused(deps.storage, Operation::Swap)?,
+    })
+}
+
+fn query_pair(deps: Deps, base_token: String) -> Result<PairResponse, ContractError> {
+    Ok(pair_response(load_pair(deps.storage, &base_token)?))
+}
+
+fn query_pairs(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<PairsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.as_deref().map(Bound::exclusive);
+    let pairs = PAIRS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| Ok(pair_response(item?.1)))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(PairsResponse { pairs })
+}
+
+fn pair_response(pair: Pair) -> PairResponse {
+    PairResponse {
+        base_token: pair.base_token,
+        quote_token: pair.quote_token,
+        base_decimals: pair.base_decimals,
+        quote_decimals: pair.quote_decimals,
+        rate_source: pair.rate_source,
+        sell: pair.sell,
+        buy: pair.buy,
+        swap_fee_bps: pair.swap_fee_bps,
+        circuit_breaker: pair.circuit_breaker,
+        volume_limits: pair.volume_limits,
+        rate_delay_seconds: pair.rate_delay_seconds,
+        keeper_tip_bps: pair.keeper_tip_bps,
+        enabled: pair.enabled,
+        paused: pair.paused,
+    }
+}
+
+fn query_exchange_rate(
+    deps: Deps,
+    env: Env,
+    base_token: String,
+) -> Result<ExchangeRateResponse, ContractError> {
+    let pair = load_pair(deps.storage, &base_token)?;
+    let (rate, last_updated) = latest_rate(deps, &env, &pair)?;
+    Ok(ExchangeRateResponse { rate, last_updated })
+}
+
+fn query_pending_rate(deps: Deps, env: Env) -> StdResult<PendingRateResponse> {
+    let rates = SCHEDULED_RATES
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter_map(|item| match item {
+            Ok((base_token, scheduled)) if scheduled.effective_at > env.block.time => {
+                Some(Ok(PendingRate {
+                    base_token,
+                    rate: scheduled.rate,
+                    effective_at: scheduled.effective_at,
+                }))
+            }
+            Ok(_) => None,
+            Err(err) => Some(Err(err)),
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(PendingRateResponse { rates })
+}
+
+fn query_rate_history(
+    deps: Deps,
+    env: Env,
+    base_token: String,
+    start: Option<Timestamp>,
+    end: Option<Timestamp>,
+    limit: Option<u32>,
+) -> StdResult<RateHistoryResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    // Announced rates are recorded ahead of time but only show once in effect
+    let end = end.map_or(env.block.time, |end| end.min(env.block.time));
+    let rates = rate_history(deps.storage, &base_token, start, Some(end), limit)?;
+
+    Ok(RateHistoryResponse { rates })
+}
+
+fn query_twap(
+    deps: Deps,
+    env: Env,
+    base_token: String,
+    window_seconds: u64,
+) -> Result<TwapResponse, ContractError> {
+    let pair = load_pair(deps.storage, &base_token)?;
+    let (twap, observations) = twap(deps.storage, &env, pair.base_token.as_str(), window_seconds)?;
+
+    Ok(TwapResponse {
+        twap,
+        window_seconds,
+        observations,
+    })
+}
+
+fn query_simulation(
+    deps: Deps,
+    env: Env,
+    offer_asset: String,
+    ask_asset: Option<String>,
+    amount: Uint128,
+) -> Result<SimulationResponse, ContractError> {
+    let (pair, direction) = route(deps.storage, Some(&offer_asset), ask_asset.as_deref())?;
+    let rate = current_rate(deps, &env, &pair)?;
+    let outcome = simulate(&pair, direction, rate, amount)?;
+
+    Ok(SimulationResponse {
+        return_amount: outcome.return_amount,
+        spread_amount: outcome.spread_amount,
+        fee_amount: outcome.fee_amount,
+        protocol_fee_amount: outcome.protocol_fee_amount,
+        rate,
+    })
+}
+
+fn query_reverse_simulation(
+    deps: Deps,
+    env: Env,
+    ask_asset: String,
+    offer_asset: Option<String>,
+    amount: Uint128,
+) -> Result<ReverseSimulationResponse, ContractError> {
+    let (pair, direction) = route(deps.storage, offer_asset.as_deref(), Some(&ask_asset))?;
+    let rate = current_rate(deps, &env, &pair)?;
+    let offer_amount = reverse_simulate(&pair, direction, rate, amount)?;
+    // Run the offer forward so the reported deductions match what the swap would take
+    let outcome = simulate(&pair, direction, rate, offer_amount)?;
+
+    Ok(ReverseSimulationResponse {
+        offer_amount,
+        spread_amount: outcome.spread_amount,
+        fee_amount: outcome.fee_amount,
+        protocol_fee_amount: outcome.protocol_fee_amount,
+        rate,
+    })
+}
+
+fn load_pair(storage: &dyn Storage, base_token: &str) -> Result<Pair, ContractError> {
+    PAIRS
+        .may_load(storage, base_token)?
+        .ok_or_else(|| ContractError::PairNotFound {
+            token: base_token.to_string(),
+        })
+}
+
+fn load_asset(storage: &dyn Storage, token: &str) -> Result<AssetInfo, ContractError> {
+    ASSETS
+        .may_load(storage, token)?
+        .ok_or_else(|| ContractError::InvalidToken {
+            token: token.to_string(),
+        })
+}
+
+fn validate_asset(api: &dyn Api, asset: AssetInfo) -> Result<AssetInfo, ContractError> {
+    match asset {
+        AssetInfo::Native { denom } if denom.is_empty() => Err(ContractError::InvalidAsset {
+            reason: "denom must not be empty".to_string(),
+        }),
+        AssetInfo::Native { denom } => Ok(AssetInfo::Native { denom }),
+        AssetInfo::Cw20 { addr } => Ok(AssetInfo::Cw20 {
+            addr: api.addr_validate(&addr)?.to_string(),
+        }),
+    }
+}
+
+// CW20 decimals come from the token itself, a native denom's must be given
+fn asset_decimals(
+    querier: &QuerierWrapper,
+    asset: &AssetInfo,
+    decimals: Option<u8>,
+) -> Result<u8, ContractError> {
+    let decimals = match asset {
+        AssetInfo::Cw20 { addr } => {
+            let info: TokenInfoResponse =
+                querier.query_wasm_smart(addr, &Cw20QueryMsg::TokenInfo {})?;
+            info.decimals
+        }
+        AssetInfo::Native { denom } => decimals.ok_or_else(|| ContractError::InvalidAsset {
+            reason: format!("decimals of native denom {denom} must be given"),
+        })?,
+    };
+    if decimals > MAX_TOKEN_DECIMALS {
+        return Err(ContractError::InvalidAsset {
+            reason: format!(
+                "{} has {decimals} decimals, at most {MAX_TOKEN_DECIMALS} are supported",
+                asset.as_str()
+            ),
+        });
+    }
+    Ok(decimals)
+}
+
+// Native swaps take exactly one coin
+fn one_coin(info: &MessageInfo) -> Result<Asset, ContractError> {
+    match info.funds.as_slice() {
+        [coin] if !coin.amount.is_zero() => Ok(native_asset(coin.clone())),
+        _ => Err(ContractError::InsufficientFunds {
+            expected: "exactly one non-zero coin".to_string(),
+        }),
+    }
+}
+
+fn native_asset(coin: Coin) -> Asset {
+    Asset {
+        info: AssetInfo::Native { denom: coin.denom },
+        amount: coin.amount,
+    }
+}
+
+fn add_reserve(storage: &mut dyn Storage, token: &str, amount: Uint128) -> StdResult<()> {
+    RESERVES.update(storage, token, |reserve| -> StdResult<_> {
+        Ok(reserve.unwrap_or_default().checked_add(amount)?)
+    })?;
+    Ok(())
+}
+
+fn take_reserve(
+    storage: &mut dyn Storage,
+    token: &str,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    let available = RESERVES.may_load(storage, token)?.unwrap_or_default();
+    if amount > available {
+        return Err(ContractError::InsufficientReserve {
+            token: token.to_string(),
+            requested: amount,
+            available,
+        });
+    }
+    RESERVES.save(storage, token, &(available - amount))?;
+    Ok(())
+}
+
+fn transfer_msg(asset: &AssetInfo, recipient: String, amount: Uint128) -> StdResult<CosmosMsg> {
+    Ok(match asset {
+        AssetInfo::Native { denom } => BankMsg::Send {
+            to_address: recipient,
+            amount: vec![Coin {
+                denom: denom.clone(),
+                amount,
+            }],
+        }
+        .into(),
+        AssetInfo::Cw20 { addr } => WasmMsg::Execute {
+            contract_addr: addr.clone(),
+            msg: to_json_binary(&Cw20ExecuteMsg::Transfer { recipient, amount })?,
+            funds: vec![],
+        }
+        .into(),
+    })
+}
+
+fn ensure_min_output(output: Uint128, min_output: Option<Uint128>) -> Result<(), ContractError> {
+    match min_output {
+        Some(min_output) if output < min_output => {
+            Err(ContractError::SlippageExceeded { min_output, output })
+        }
+        _ => Ok(()),
+    }
+}
+
+fn query_feeders(deps: Deps, base_token: String) -> StdResult<FeedersResponse> {
+    let feeders = FEEDERS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .map(|address| {
+            let address = address?;
+            let submission = SUBMISSIONS.may_load(deps.storage, (&base_token, &address))?;
+            Ok(FeederInfo {
+                address: address.to_string(),
+                price: submission.as_ref().map(|submission| submission.price),
+                submitted_at: submission.map(|submission| submission.submitted_at),
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(FeedersResponse { feeders })
+}
+
+fn query_remaining_allowance(
+    deps: Deps,
+    env: Env,
+    base_token: String,
+    address: String,
+) -> Result<RemainingAllowanceResponse, ContractError> {
+    let pair = load_pair(deps.storage, &base_token)?;
+    let address = deps.api.addr_validate(&address)?;
+    let allowance = remaining_allowance(deps.storage, &env, &pair, &address)?;
+
+    Ok(RemainingAllowanceResponse {
+        market_maker: MARKET_MAKERS.has(deps.storage, &address),
+        daily_remaining: allowance.daily,
+        epoch_remaining: allowance.epoch,
+    })
+}
+
+fn query_reserve_status(deps: Deps, env: Env) -> Result<ReserveStatusResponse, ContractError> {
+    let backings = RESERVE_BACKING
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    let pairs = backings
+        .into_iter()
+        .map(|(base_token, backing)| {
+            let pair = load_pair(deps.storage, &base_token)?;
+            reserve_status(deps, &env, &pair, &backing)
+        })
+        .collect::<Result<Vec<_>, ContractError>>()?;
+
+    Ok(ReserveStatusResponse { pairs })
+}
+
+fn query_trade(deps: Deps, id: u64) -> Result<Trade, ContractError> {
+    TRADES
+        .may_load(deps.storage, id)?
+        .ok_or(ContractError::TradeNotFound { id })
+}
+
+fn query_trades_by_user(
+    deps: Deps,
+    user: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<TradesResponse> {
+    let user = deps.api.addr_validate(&user)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let trades = trades_by_user(deps.storage, &user, start_after, limit)?;
+
+    Ok(TradesResponse { trades })
+}
+
+fn query_order(deps: Deps, id: u64) -> Result<LimitOrder, ContractError> {
+    ORDERS
+        .may_load(deps.storage, id)?
+        .ok_or(ContractError::OrderNotFound { id })
+}
+
+fn query_order_book(
+    deps: Deps,
+    base_token: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> Result<OrdersResponse, ContractError> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_after = start_after.map(|id| query_order(deps, id)).transpose()?;
+    let orders = order_book(deps.storage, &base_token, start_after.as_ref(), None, limit)?;
+
+    Ok(OrdersResponse { orders })
+}
+
+fn query_orders_by_user(
+    deps: Deps,
+    user: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<OrdersResponse> {
+    let user = deps.api.addr_validate(&user)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let orders = orders_by_user(deps.storage, &user, start_after, limit)?;
+
+    Ok(OrdersResponse { orders })
+}
+
+fn query_dca_plans_by_user(
+    deps: Deps,
+    user: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<DcaPlansResponse> {
+    let user = deps.api.addr_validate(&user)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let plans = plans_by_user(deps.storage, &user, start_after, limit)?;
+
+    Ok(DcaPlansResponse { plans })
+}
+
+fn query_collected_fees(deps: Deps) -> StdResult<CollectedFeesResponse> {
+    let fees = COLLECTED_FEES
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (token, amount) = item?;
+            Ok(TokenAmount { token, amount })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(CollectedFeesResponse { fees })
+}
+
+fn query_reserves(deps: Deps) -> StdResult<ReservesResponse> {
+    let reserves = RESERVES
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (token, amount) = item?;
+            Ok(TokenAmount { token, amount })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(ReservesResponse { reserves })
+}
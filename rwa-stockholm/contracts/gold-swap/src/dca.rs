@@ -0,0 +1,62 @@
+use cosmwasm_std::{Addr, Empty, Order, StdResult, Storage, Timestamp};
+use cw_storage_plus::Bound;
+use rwa_stockholm_packages::msg::gold_swap::DcaPlan;
+
+use crate::state::{DCA_PLANS, DCA_PLAN_COUNT, DCA_SCHEDULE, USER_DCA_PLANS};
+
+/// Stores a new plan under the next plan id and returns it with the id filled in.
+pub fn create_plan(storage: &mut dyn Storage, mut plan: DcaPlan) -> StdResult<DcaPlan> {
+    let id = DCA_PLAN_COUNT.may_load(storage)?.unwrap_or_default() + 1;
+    DCA_PLAN_COUNT.save(storage, &id)?;
+
+    plan.id = id;
+    save_plan(storage, &plan)?;
+    Ok(plan)
+}
+
+/// Stores `plan` and schedules its next swap unless it is paused. A plan that changes must be
+/// removed first so its old schedule entry goes away.
+pub fn save_plan(storage: &mut dyn Storage, plan: &DcaPlan) -> StdResult<()> {
+    DCA_PLANS.save(storage, plan.id, plan)?;
+    USER_DCA_PLANS.save(storage, (&plan.owner, plan.id), &Empty {})?;
+    if !plan.paused {
+        DCA_SCHEDULE.save(storage, schedule_key(plan), &Empty {})?;
+    }
+    Ok(())
+}
+
+pub fn remove_plan(storage: &mut dyn Storage, plan: &DcaPlan) {
+    DCA_PLANS.remove(storage, plan.id);
+    USER_DCA_PLANS.remove(storage, (&plan.owner, plan.id));
+    DCA_SCHEDULE.remove(storage, schedule_key(plan));
+}
+
+/// Active plans whose next swap is due at `now`, the longest overdue first.
+pub fn due_plans(storage: &dyn Storage, now: Timestamp, limit: usize) -> StdResult<Vec<DcaPlan>> {
+    let max = Bound::inclusive((now.seconds(), u64::MAX));
+    DCA_SCHEDULE
+        .keys(storage, None, Some(max), Order::Ascending)
+        .take(limit)
+        .map(|key| DCA_PLANS.load(storage, key?.1))
+        .collect()
+}
+
+/// Plans of `user` with an id above `start_after`, oldest first.
+pub fn plans_by_user(
+    storage: &dyn Storage,
+    user: &Addr,
+    start_after: Option<u64>,
+    limit: usize,
+) -> StdResult<Vec<DcaPlan>> {
+    let start = start_after.map(Bound::exclusive);
+    USER_DCA_PLANS
+        .prefix(user)
+        .keys(storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|id| DCA_PLANS.load(storage, id?))
+        .collect()
+}
+
+fn schedule_key(plan: &DcaPlan) -> (u64, u64) {
+    (plan.next_swap_at.seconds(), plan.id)
+}
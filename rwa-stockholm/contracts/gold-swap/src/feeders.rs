@@ -0,0 +1,72 @@
+use cosmwasm_std::{Decimal, Env, Order, StdResult, Storage, Timestamp, Uint128};
+
+use crate::math::{abs_diff, bps_of};
+use crate::state::{PriceSubmission, FEEDERS, SUBMISSIONS};
+use crate::ContractError;
+
+/// Aggregate of the submissions in the current round.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoundPrice {
+    pub price: Decimal,
+    pub submissions: u32,  // Fresh submissions left after dropping outliers
+    pub latest: Timestamp, // Newest of those submissions
+}
+
+/// Takes every whitelisted feeder's submission for the pair from the last `round_seconds`,
+/// drops the ones further than `max_deviation_bps` from their median, and returns the median
+/// of the rest. `None` if no submission is left.
+pub fn round_price(
+    storage: &dyn Storage,
+    env: &Env,
+    base_token: &str,
+    round_seconds: u64,
+    max_deviation_bps: u16,
+) -> Result<Option<RoundPrice>, ContractError> {
+    let round_start = env.block.time.seconds().saturating_sub(round_seconds);
+    let mut fresh = SUBMISSIONS
+        .prefix(base_token)
+        .range(storage, None, None, Order::Ascending)
+        .filter_map(|item| match item {
+            // Submissions of removed feeders are kept in storage but no longer count
+            Ok((feeder, submission))
+                if submission.submitted_at.seconds() >= round_start
+                    && FEEDERS.has(storage, &feeder) =>
+            {
+                Some(Ok(submission))
+            }
+            Ok(_) => None,
+            Err(err) => Some(Err(err)),
+        })
+        .collect::<StdResult<Vec<PriceSubmission>>>()?;
+    if fresh.is_empty() {
+        return Ok(None);
+    }
+    fresh.sort_by_key(|submission| submission.price);
+
+    let reference = median(&fresh);
+    let band = bps_of(reference, max_deviation_bps)?;
+    fresh.retain(|submission| abs_diff(submission.price, reference) <= band);
+
+    // With an even count the reference can fall between two submissions far enough apart
+    // that neither is within the band
+    let Some(latest) = fresh.iter().map(|submission| submission.submitted_at).max() else {
+        return Ok(None);
+    };
+
+    Ok(Some(RoundPrice {
+        price: median(&fresh),
+        submissions: fresh.len() as u32,
+        latest,
+    }))
+}
+
+/// Median of submissions sorted by price; the mean of the middle two for an even count.
+fn median(sorted: &[PriceSubmission]) -> Decimal {
+    let mid = sorted.len() / 2;
+    if sorted.len() % 2 == 1 {
+        sorted[mid].price
+    } else {
+        let (low, high) = (sorted[mid - 1].price, sorted[mid].price);
+        low + (high - low) / Uint128::new(2)
+    }
+}
@@ -0,0 +1,98 @@
+use cosmwasm_std::{Decimal, Env, Order, StdResult, Storage, Timestamp, Uint128};
+use cw_storage_plus::Bound;
+use rwa_stockholm_packages::msg::gold_swap::RatePoint;
+
+use crate::state::RATE_HISTORY;
+use crate::ContractError;
+
+/// Records `rate` as the pair's rate from `time` on. A second update in the same block
+/// replaces the first.
+pub fn record_rate(
+    storage: &mut dyn Storage,
+    base_token: &str,
+    time: Timestamp,
+    rate: Decimal,
+) -> StdResult<()> {
+    RATE_HISTORY.save(storage, (base_token, time.seconds()), &rate)
+}
+
+/// Recorded rates between `start` and `end`, both inclusive, oldest first.
+pub fn rate_history(
+    storage: &dyn Storage,
+    base_token: &str,
+    start: Option<Timestamp>,
+    end: Option<Timestamp>,
+    limit: usize,
+) -> StdResult<Vec<RatePoint>> {
+    let min = start.map(|start| Bound::inclusive(start.seconds()));
+    let max = end.map(|end| Bound::inclusive(end.seconds()));
+    RATE_HISTORY
+        .prefix(base_token)
+        .range(storage, min, max, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (seconds, rate) = item?;
+            Ok(RatePoint {
+                timestamp: Timestamp::from_seconds(seconds),
+                rate,
+            })
+        })
+        .collect()
+}
+
+/// Time-weighted average of the recorded rate over the last `window_seconds`, and the number of
+/// recorded rates it covers. Each rate counts for as long as it was in effect; if the history
+/// starts inside the window, so does the average.
+pub fn twap(
+    storage: &dyn Storage,
+    env: &Env,
+    base_token: &str,
+    window_seconds: u64,
+) -> Result<(Decimal, u32), ContractError> {
+    let now = env.block.time.seconds();
+    let window_start = now.saturating_sub(window_seconds);
+    let history = RATE_HISTORY.prefix(base_token);
+
+    // The rate already in effect when the window opened
+    let opening = history
+        .range(
+            storage,
+            None,
+            Some(Bound::inclusive(window_start)),
+            Order::Descending,
+        )
+        .next()
+        .transpose()?
+        .map(|(_, rate)| (window_start, rate));
+    let points = opening
+        .into_iter()
+        .map(Ok)
+        .chain(history.range(
+            storage,
+            Some(Bound::exclusive(window_start)),
+            Some(Bound::inclusive(now)),
+            Order::Ascending,
+        ))
+        .collect::<StdResult<Vec<(u64, Decimal)>>>()?;
+
+    let Some(&(first, _)) = points.first() else {
+        return Err(ContractError::RateNotSet {});
+    };
+    let mut weighted = Decimal::zero();
+    for (index, (since, rate)) in points.iter().enumerate() {
+        let until = points.get(index + 1).map_or(now, |(next, _)| *next);
+        weighted += rate.checked_mul(seconds(until - since))?;
+    }
+
+    let observations = points.len() as u32;
+    let covered = now - first;
+    if covered == 0 {
+        // Nothing to weigh yet, so the latest rate is the average
+        return Ok((points[points.len() - 1].1, observations));
+    }
+    Ok((weighted / Uint128::from(covered), observations))
+}
+
+fn seconds(seconds: u64) -> Decimal {
+    Decimal::from_ratio(seconds, 1u64)
+}
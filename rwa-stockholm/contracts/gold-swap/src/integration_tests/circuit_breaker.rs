@@ -0,0 +1,95 @@
+use super::*;
+
+fn breaker_suite(circuit_breaker: CircuitBreaker) -> Suite {
+    let mut pair = pair_config(RateSource::Fixed {});
+    pair.circuit_breaker = circuit_breaker;
+    Suite::new(pair)
+}
+
+#[test]
+fn single_update_cannot_move_the_rate_too_far() {
+    let mut suite = breaker_suite(CircuitBreaker {
+        max_rate_change_bps: Some(1_000),
+        ..Default::default()
+    });
+    // The first rate has nothing to be compared against
+    suite.set_rate("2").unwrap();
+
+    let err = suite.set_rate("2.3").unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::RateChangeTooLarge {
+            previous: Decimal::percent(200),
+            proposed: Decimal::percent(230),
+            max_change_bps: 1_000,
+        }
+    );
+    suite.set_rate("1.8").unwrap();
+}
+
+#[test]
+fn rate_updates_are_limited_per_window() {
+    let mut suite = breaker_suite(CircuitBreaker {
+        max_updates_per_window: Some(2),
+        window_seconds: 3600,
+        ..Default::default()
+    });
+    suite.set_rate("2").unwrap();
+    suite.advance(1800);
+    suite.set_rate("2.1").unwrap();
+
+    let err = suite.set_rate("2.2").unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::TooManyRateUpdates {
+            max_updates: 2,
+            window_seconds: 3600,
+        }
+    );
+    // The first update leaves the window
+    suite.advance(1800);
+    suite.set_rate("2.2").unwrap();
+}
+
+#[test]
+fn oracle_jump_pauses_the_pair() {
+    let (mut suite, oracle) = oracle_suite("2");
+    suite
+        .execute(
+            ADMIN,
+            &ExecuteMsg::UpdatePair {
+                base_token: GOLD.to_string(),
+                update: PairUpdate {
+                    circuit_breaker: Some(CircuitBreaker {
+                        auto_pause_bps: Some(1_000),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+            },
+            &[],
+        )
+        .unwrap();
+    suite.swap(ALICE, coin(1_000_000, GOLD)).unwrap();
+
+    // The swap that sees the jump trips the breaker and gets its offer back
+    set_price(&mut suite.app, &oracle, "2.5", "0.001");
+    suite.swap(ALICE, coin(1_000_000, GOLD)).unwrap();
+    assert_eq!(suite.balance(ALICE, GOLD), 99_000_000);
+    assert_eq!(suite.balance(ALICE, OM), 102_000_000);
+    let err = suite.swap(ALICE, coin(1_000_000, GOLD)).unwrap_err();
+    assert_eq!(err, ContractError::Paused {});
+
+    // Unpausing accepts the new price as the baseline
+    suite
+        .execute(
+            ADMIN,
+            &ExecuteMsg::Unpause {
+                base_token: Some(GOLD.to_string()),
+            },
+            &[],
+        )
+        .unwrap();
+    suite.swap(ALICE, coin(1_000_000, GOLD)).unwrap();
+    assert_eq!(suite.balance(ALICE, OM), 104_500_000);
+}
@@ -0,0 +1,113 @@
+use rwa_stockholm_packages::msg::gold_swap::DcaPlansResponse;
+
+use crate::math::MAX_KEEPER_TIP_BPS;
+
+use super::*;
+
+fn create_plan(suite: &mut Suite, max_swaps: u32) -> u64 {
+    suite
+        .execute(
+            ALICE,
+            &ExecuteMsg::CreateDcaPlan {
+                base_token: GOLD.to_string(),
+                per_swap_amount: Uint128::new(2_000_000),
+                interval_seconds: 3600,
+                max_swaps,
+            },
+            &coins(2_000_000 * max_swaps as u128, OM),
+        )
+        .unwrap();
+    let plans: DcaPlansResponse = suite.query(&QueryMsg::DcaPlansByUser {
+        user: ALICE.to_string(),
+        start_after: None,
+        limit: None,
+    });
+    plans.plans.last().unwrap().id
+}
+
+#[test]
+fn keeper_runs_due_dca_swaps() {
+    let mut pair = pair_config(RateSource::Fixed {});
+    pair.keeper_tip_bps = 100;
+    let mut suite = Suite::new(pair);
+    suite.set_rate("2").unwrap();
+
+    create_plan(&mut suite, 2);
+
+    // The first swap is due right away: 2 OM less a 1% tip buys 0.99 gold
+    suite
+        .execute(KEEPER, &ExecuteMsg::ExecuteDue { limit: None }, &[])
+        .unwrap();
+    assert_eq!(suite.balance(ALICE, GOLD), 100_990_000);
+    assert_eq!(suite.balance(KEEPER, OM), 20_000);
+
+    // Nothing is due again until the interval has passed
+    suite
+        .execute(KEEPER, &ExecuteMsg::ExecuteDue { limit: None }, &[])
+        .unwrap();
+    assert_eq!(suite.balance(ALICE, GOLD), 100_990_000);
+
+    suite.advance(3600);
+    suite
+        .execute(KEEPER, &ExecuteMsg::ExecuteDue { limit: None }, &[])
+        .unwrap();
+    assert_eq!(suite.balance(ALICE, GOLD), 101_980_000);
+    assert_eq!(suite.balance(KEEPER, OM), 40_000);
+
+    // The finished plan is gone
+    let plans: DcaPlansResponse = suite.query(&QueryMsg::DcaPlansByUser {
+        user: ALICE.to_string(),
+        start_after: None,
+        limit: None,
+    });
+    assert!(plans.plans.is_empty());
+}
+
+#[test]
+fn cancelled_plan_refunds_the_unspent_deposit() {
+    let mut suite = Suite::new(pair_config(RateSource::Fixed {}));
+    suite.set_rate("2").unwrap();
+    let id = create_plan(&mut suite, 3);
+
+    suite
+        .execute(KEEPER, &ExecuteMsg::ExecuteDue { limit: None }, &[])
+        .unwrap();
+    suite
+        .execute(ALICE, &ExecuteMsg::PauseDcaPlan { id }, &[])
+        .unwrap();
+    suite.advance(3600);
+    suite
+        .execute(KEEPER, &ExecuteMsg::ExecuteDue { limit: None }, &[])
+        .unwrap();
+    // The paused plan was skipped
+    assert_eq!(suite.balance(ALICE, GOLD), 101_000_000);
+
+    let cancel = ExecuteMsg::CancelDcaPlan { id };
+    let err = suite.execute(BOB, &cancel, &[]).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    suite.execute(ALICE, &cancel, &[]).unwrap();
+    // Two of the three swaps were never run
+    assert_eq!(suite.balance(ALICE, OM), 98_000_000);
+}
+
+#[test]
+fn keeper_tip_is_capped() {
+    let mut pair = pair_config(RateSource::Fixed {});
+    pair.keeper_tip_bps = MAX_KEEPER_TIP_BPS;
+    let mut suite = Suite::new(pair);
+
+    let err = suite
+        .execute(
+            ADMIN,
+            &ExecuteMsg::UpdatePair {
+                base_token: GOLD.to_string(),
+                update: PairUpdate {
+                    keeper_tip_bps: Some(MAX_KEEPER_TIP_BPS + 1),
+                    ..Default::default()
+                },
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert!(matches!(err, ContractError::InvalidSwapParams { .. }));
+}
@@ -0,0 +1,102 @@
+use rwa_stockholm_packages::msg::gold_swap::ExchangeRateResponse;
+
+use super::*;
+
+const FEEDERS: [&str; 3] = ["feeder1", "feeder2", "feeder3"];
+
+fn feeder_suite() -> Suite {
+    let mut suite = Suite::new(pair_config(RateSource::Feeders {
+        quorum: 2,
+        round_seconds: 300,
+        max_deviation_bps: 500,
+    }));
+    for feeder in FEEDERS {
+        suite
+            .execute(
+                ADMIN,
+                &ExecuteMsg::AddFeeder {
+                    address: feeder.to_string(),
+                },
+                &[],
+            )
+            .unwrap();
+    }
+    suite
+}
+
+fn submit(suite: &mut Suite, feeder: &str, price: &str) -> Result<AppResponse, ContractError> {
+    suite.execute(
+        feeder,
+        &ExecuteMsg::SubmitPrice {
+            base_token: GOLD.to_string(),
+            price: price.parse().unwrap(),
+        },
+        &[],
+    )
+}
+
+#[test]
+fn swaps_use_the_median_without_outliers() {
+    let mut suite = feeder_suite();
+    submit(&mut suite, FEEDERS[0], "2").unwrap();
+    submit(&mut suite, FEEDERS[1], "2.01").unwrap();
+    // More than 5% off the median of all three, so it is dropped
+    submit(&mut suite, FEEDERS[2], "3").unwrap();
+
+    let rate: ExchangeRateResponse = suite.query(&QueryMsg::ExchangeRate {
+        base_token: GOLD.to_string(),
+    });
+    assert_eq!(rate.rate, Decimal::permille(2005));
+    suite.swap(ALICE, coin(1_000_000, GOLD)).unwrap();
+    assert_eq!(suite.balance(ALICE, OM), 102_005_000);
+}
+
+#[test]
+fn swaps_wait_for_a_quorum_of_fresh_prices() {
+    let mut suite = feeder_suite();
+    submit(&mut suite, FEEDERS[0], "2").unwrap();
+
+    let err = suite.swap(ALICE, coin(1_000_000, GOLD)).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::QuorumNotMet {
+            submissions: 1,
+            quorum: 2
+        }
+    );
+    submit(&mut suite, FEEDERS[1], "2").unwrap();
+    suite.swap(ALICE, coin(1_000_000, GOLD)).unwrap();
+
+    // Once the round has passed the submissions no longer count
+    suite.advance(301);
+    let err = suite.swap(ALICE, coin(1_000_000, GOLD)).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::QuorumNotMet {
+            submissions: 0,
+            quorum: 2
+        }
+    );
+}
+
+#[test]
+fn submissions_need_a_feeder_pair_and_a_positive_price() {
+    let mut suite = feeder_suite();
+    let err = submit(&mut suite, ALICE, "2").unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    let err = submit(&mut suite, FEEDERS[0], "0").unwrap_err();
+    assert!(matches!(err, ContractError::InvalidPriceSubmission { .. }));
+
+    let mut suite = Suite::new(pair_config(RateSource::Fixed {}));
+    suite
+        .execute(
+            ADMIN,
+            &ExecuteMsg::AddFeeder {
+                address: FEEDERS[0].to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    let err = submit(&mut suite, FEEDERS[0], "2").unwrap_err();
+    assert!(matches!(err, ContractError::InvalidPriceSubmission { .. }));
+}
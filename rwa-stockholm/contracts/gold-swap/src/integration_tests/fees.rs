@@ -0,0 +1,77 @@
+use rwa_stockholm_packages::msg::gold_swap::{CollectedFeesResponse, TokenAmount};
+use rwa_stockholm_packages::ownership::Role;
+
+use super::*;
+
+const TREASURY: &str = "treasury";
+
+fn fee_suite() -> Suite {
+    let mut pair = pair_config(RateSource::Fixed {});
+    pair.swap_fee_bps = 100;
+    let mut suite = Suite::new(pair);
+    suite.set_rate("1").unwrap();
+    suite
+        .execute(
+            ADMIN,
+            &ExecuteMsg::SetRole {
+                role: Role::Treasury,
+                address: Some(TREASURY.to_string()),
+            },
+            &[],
+        )
+        .unwrap();
+    suite
+}
+
+fn collected_fees(suite: &Suite) -> Vec<TokenAmount> {
+    let response: CollectedFeesResponse = suite.query(&QueryMsg::CollectedFees {});
+    response.fees
+}
+
+#[test]
+fn swap_fees_accrue_per_token() {
+    let mut suite = fee_suite();
+
+    let res = suite.swap(ALICE, coin(1_000_000, GOLD)).unwrap();
+    assert_eq!(suite.balance(ALICE, OM), 100_990_000);
+    let wasm_event = res.events.iter().find(|event| event.ty == "wasm").unwrap();
+    assert!(wasm_event
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "protocol_fee_amount" && attr.value == "10000"));
+
+    // Buying takes its fee in the base token
+    suite.swap(BOB, coin(1_000_000, OM)).unwrap();
+    assert_eq!(
+        collected_fees(&suite),
+        vec![
+            TokenAmount {
+                token: GOLD.to_string(),
+                amount: Uint128::new(10_000),
+            },
+            TokenAmount {
+                token: OM.to_string(),
+                amount: Uint128::new(10_000),
+            },
+        ]
+    );
+}
+
+#[test]
+fn treasury_withdraws_the_collected_fees() {
+    let mut suite = fee_suite();
+    suite.swap(ALICE, coin(1_000_000, GOLD)).unwrap();
+
+    let withdraw = ExecuteMsg::WithdrawFees {
+        to: TREASURY.to_string(),
+    };
+    let err = suite.execute(ALICE, &withdraw, &[]).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    suite.execute(TREASURY, &withdraw, &[]).unwrap();
+
+    assert_eq!(suite.balance(TREASURY, OM), 10_000);
+    assert!(collected_fees(&suite).is_empty());
+    // Nothing is left to send twice
+    suite.execute(TREASURY, &withdraw, &[]).unwrap();
+    assert_eq!(suite.balance(TREASURY, OM), 10_000);
+}
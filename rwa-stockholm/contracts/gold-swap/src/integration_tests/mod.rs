@@ -0,0 +1,283 @@
+use cosmwasm_std::{coin, coins, Addr, Coin, Decimal, Empty, Uint128};
+use cw_multi_test::{App, AppResponse, Contract, ContractWrapper, Executor};
+use rwa_stockholm_packages::msg::gold_swap::{
+    AssetInfo, CircuitBreaker, ExecuteMsg, InstantiateMsg, LimitOrder, OrdersResponse, PairConfig,
+    PairUpdate, QueryMsg, RateSource, SwapParams, VolumeLimits,
+};
+use rwa_stockholm_packages::msg::oracle;
+
+use crate::ContractError;
+
+mod circuit_breaker;
+mod dca;
+mod feeders;
+mod fees;
+mod native_assets;
+mod oracle_rate;
+mod orders;
+mod ownership;
+mod pause;
+mod proof_of_reserves;
+mod rate_history;
+mod reserves;
+mod simulation;
+mod timelock;
+mod trades;
+mod volume_limits;
+
+const GOLD: &str = "ugold";
+const OM: &str = "uom";
+const ADMIN: &str = "admin";
+const GUARDIAN: &str = "guardian";
+const ALICE: &str = "alice";
+const BOB: &str = "bob";
+const KEEPER: &str = "keeper";
+const XAU_OM: &str = "XAU/OM";
+
+fn gold_swap_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        crate::contract::execute,
+        crate::contract::instantiate,
+        crate::contract::query,
+    );
+    Box::new(contract)
+}
+
+fn oracle_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        mock_oracle::contract::execute,
+        mock_oracle::contract::instantiate,
+        mock_oracle::contract::query,
+    );
+    Box::new(contract)
+}
+
+fn pair_config(rate_source: RateSource) -> PairConfig {
+    PairConfig {
+        base_token: AssetInfo::Native {
+            denom: GOLD.to_string(),
+        },
+        quote_token: AssetInfo::Native {
+            denom: OM.to_string(),
+        },
+        base_decimals: Some(6),
+        quote_decimals: Some(6),
+        rate_source,
+        sell: SwapParams::default(),
+        buy: SwapParams::default(),
+        swap_fee_bps: 0,
+        circuit_breaker: CircuitBreaker::default(),
+        volume_limits: VolumeLimits::default(),
+        rate_delay_seconds: 0,
+        keeper_tip_bps: 0,
+    }
+}
+
+struct Suite {
+    app: App,
+    swap: Addr,
+}
+
+impl Suite {
+    /// A gold/OM pair with both reserves funded and Alice and Bob holding both tokens.
+    fn new(pair: PairConfig) -> Self {
+        let mut app = App::new(|router, _, storage| {
+            for (addr, balance) in [
+                (ADMIN, 1_000_000_000u128),
+                (ALICE, 100_000_000),
+                (BOB, 100_000_000),
+            ] {
+                router
+                    .bank
+                    .init_balance(
+                        storage,
+                        &Addr::unchecked(addr),
+                        vec![coin(balance, GOLD), coin(balance, OM)],
+                    )
+                    .unwrap();
+            }
+        });
+
+        let code_id = app.store_code(gold_swap_contract());
+        let swap = app
+            .instantiate_contract(
+                code_id,
+                Addr::unchecked(ADMIN),
+                &InstantiateMsg {
+                    admin: ADMIN.to_string(),
+                    rate_setter: None,
+                    pauser: None,
+                    treasury: None,
+                    guardian: GUARDIAN.to_string(),
+                },
+                &[],
+                "gold-swap",
+                None,
+            )
+            .unwrap();
+
+        let mut suite = Suite { app, swap };
+        suite
+            .execute(ADMIN, &ExecuteMsg::AddPair { pair }, &[])
+            .unwrap();
+        suite
+            .execute(
+                ADMIN,
+                &ExecuteMsg::FundReserve {},
+                &[coin(100_000_000, GOLD), coin(100_000_000, OM)],
+            )
+            .unwrap();
+        suite
+    }
+
+    fn execute(
+        &mut self,
+        sender: &str,
+        msg: &ExecuteMsg,
+        funds: &[Coin],
+    ) -> Result<AppResponse, ContractError> {
+        self.app
+            .execute_contract(Addr::unchecked(sender), self.swap.clone(), msg, funds)
+            .map_err(|err| err.downcast().unwrap())
+    }
+
+    fn set_rate(&mut self, rate: &str) -> Result<AppResponse, ContractError> {
+        self.execute(
+            ADMIN,
+            &ExecuteMsg::SetExchangeRate {
+                base_token: GOLD.to_string(),
+                rate: rate.parse().unwrap(),
+            },
+            &[],
+        )
+    }
+
+    fn place_order(&mut self, sender: &str, amount: u128, min_rate: &str) -> u64 {
+        self.execute(
+            sender,
+            &ExecuteMsg::PlaceLimitOrder {
+                min_rate: min_rate.parse().unwrap(),
+                expires_at: None,
+            },
+            &coins(amount, GOLD),
+        )
+        .unwrap();
+        self.order_book().last().unwrap().id
+    }
+
+    fn execute_orders(&mut self, limit: u32) -> AppResponse {
+        self.execute(
+            KEEPER,
+            &ExecuteMsg::ExecuteOrders {
+                base_token: GOLD.to_string(),
+                limit: Some(limit),
+            },
+            &[],
+        )
+        .unwrap()
+    }
+
+    fn swap(&mut self, sender: &str, offer: Coin) -> Result<AppResponse, ContractError> {
+        let ask_token = (offer.denom == OM).then(|| GOLD.to_string());
+        self.execute(
+            sender,
+            &ExecuteMsg::Swap {
+                ask_token,
+                min_output: None,
+                deadline: None,
+            },
+            &[offer],
+        )
+    }
+
+    fn advance(&mut self, seconds: u64) {
+        self.app
+            .update_block(|block| block.time = block.time.plus_seconds(seconds));
+    }
+
+    fn balance(&self, addr: &str, denom: &str) -> u128 {
+        self.app
+            .wrap()
+            .query_balance(addr, denom)
+            .unwrap()
+            .amount
+            .u128()
+    }
+
+    fn query<T: serde::de::DeserializeOwned>(&self, msg: &QueryMsg) -> T {
+        self.app
+            .wrap()
+            .query_wasm_smart(self.swap.clone(), msg)
+            .unwrap()
+    }
+
+    fn order_book(&self) -> Vec<LimitOrder> {
+        let response: OrdersResponse = self.query(&QueryMsg::OrderBook {
+            base_token: GOLD.to_string(),
+            start_after: None,
+            limit: None,
+        });
+        response.orders
+    }
+}
+
+/// Instantiates the mock oracle, publishing `price` for XAU/OM with a 0.1% confidence band.
+fn setup_oracle(app: &mut App, price: &str) -> Addr {
+    let code_id = app.store_code(oracle_contract());
+    let oracle = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked(ADMIN),
+            &oracle::InstantiateMsg {
+                admin: ADMIN.to_string(),
+            },
+            &[],
+            "mock-oracle",
+            None,
+        )
+        .unwrap();
+    set_price(app, &oracle, price, "0.001");
+    oracle
+}
+
+fn set_price(app: &mut App, oracle: &Addr, price: &str, confidence_share: &str) {
+    let price: Decimal = price.parse().unwrap();
+    let confidence = price * confidence_share.parse::<Decimal>().unwrap();
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        oracle.clone(),
+        &oracle::ExecuteMsg::SetPrice {
+            pair: XAU_OM.to_string(),
+            price,
+            confidence,
+            publish_time: None,
+        },
+        &[],
+    )
+    .unwrap();
+}
+
+fn oracle_suite(price: &str) -> (Suite, Addr) {
+    // The oracle address is only known once it is instantiated, so the pair starts fixed
+    let mut suite = Suite::new(pair_config(RateSource::Fixed {}));
+    let oracle = setup_oracle(&mut suite.app, price);
+    suite
+        .execute(
+            ADMIN,
+            &ExecuteMsg::UpdatePair {
+                base_token: GOLD.to_string(),
+                update: PairUpdate {
+                    rate_source: Some(RateSource::Oracle {
+                        oracle: oracle.to_string(),
+                        pair: XAU_OM.to_string(),
+                        max_staleness_seconds: 60,
+                        max_confidence_bps: 50,
+                    }),
+                    ..Default::default()
+                },
+            },
+            &[],
+        )
+        .unwrap();
+    (suite, oracle)
+}
@@ -0,0 +1,62 @@
+use super::*;
+
+fn silver_pair(base_decimals: Option<u8>) -> PairConfig {
+    let mut pair = pair_config(RateSource::Fixed {});
+    pair.base_token = AssetInfo::Native {
+        denom: "factory/issuer/usilver".to_string(),
+    };
+    pair.base_decimals = base_decimals;
+    pair
+}
+
+#[test]
+fn native_coins_swap_both_ways_through_bank_sends() {
+    let mut suite = Suite::new(pair_config(RateSource::Fixed {}));
+    suite.set_rate("2").unwrap();
+
+    suite.swap(ALICE, coin(1_000_000, GOLD)).unwrap();
+    assert_eq!(suite.balance(ALICE, GOLD), 99_000_000);
+    assert_eq!(suite.balance(ALICE, OM), 102_000_000);
+
+    suite.swap(BOB, coin(2_000_000, OM)).unwrap();
+    assert_eq!(suite.balance(BOB, GOLD), 101_000_000);
+    assert_eq!(suite.balance(BOB, OM), 98_000_000);
+}
+
+#[test]
+fn native_pair_needs_supported_decimals() {
+    let mut suite = Suite::new(pair_config(RateSource::Fixed {}));
+
+    let err = suite
+        .execute(
+            ADMIN,
+            &ExecuteMsg::AddPair {
+                pair: silver_pair(None),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert!(matches!(err, ContractError::InvalidAsset { .. }));
+
+    // More decimals than a conversion can scale would overflow every swap on the pair
+    let err = suite
+        .execute(
+            ADMIN,
+            &ExecuteMsg::AddPair {
+                pair: silver_pair(Some(19)),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert!(matches!(err, ContractError::InvalidAsset { .. }));
+
+    suite
+        .execute(
+            ADMIN,
+            &ExecuteMsg::AddPair {
+                pair: silver_pair(Some(18)),
+            },
+            &[],
+        )
+        .unwrap();
+}
@@ -0,0 +1,40 @@
+use super::*;
+
+#[test]
+fn swap_uses_oracle_price() {
+    let (mut suite, oracle) = oracle_suite("2");
+
+    suite.swap(ALICE, coin(1_000_000, GOLD)).unwrap();
+    assert_eq!(suite.balance(ALICE, OM), 102_000_000);
+    assert_eq!(suite.balance(ALICE, GOLD), 99_000_000);
+
+    // Buying pays out at the inverse rate
+    set_price(&mut suite.app, &oracle, "4", "0.001");
+    suite.swap(BOB, coin(2_000_000, OM)).unwrap();
+    assert_eq!(suite.balance(BOB, GOLD), 100_500_000);
+}
+
+#[test]
+fn stale_oracle_price_is_rejected() {
+    let (mut suite, _) = oracle_suite("2");
+
+    suite.advance(61);
+    let err = suite.swap(ALICE, coin(1_000_000, GOLD)).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::StalePrice {
+            age_seconds: 61,
+            max_staleness_seconds: 60,
+        }
+    );
+}
+
+#[test]
+fn uncertain_oracle_price_is_rejected() {
+    let (mut suite, oracle) = oracle_suite("2");
+
+    // 1% band against an allowed 0.5%
+    set_price(&mut suite.app, &oracle, "2", "0.01");
+    let err = suite.swap(ALICE, coin(1_000_000, GOLD)).unwrap_err();
+    assert!(matches!(err, ContractError::PriceUncertain { .. }));
+}
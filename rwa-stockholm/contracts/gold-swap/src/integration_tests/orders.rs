@@ -0,0 +1,105 @@
+use rwa_stockholm_packages::pause::Operation;
+
+use super::*;
+
+#[test]
+fn rate_update_fills_reached_orders() {
+    let mut suite = Suite::new(pair_config(RateSource::Fixed {}));
+    suite.set_rate("2").unwrap();
+
+    suite.place_order(ALICE, 1_000_000, "3");
+    let high = suite.place_order(BOB, 1_000_000, "5");
+
+    // Nothing is reached at the current rate
+    suite.execute_orders(10);
+    assert_eq!(suite.order_book().len(), 2);
+
+    suite.set_rate("3").unwrap();
+    let book: Vec<u64> = suite.order_book().iter().map(|order| order.id).collect();
+    assert_eq!(book, vec![high]);
+    assert_eq!(suite.balance(ALICE, OM), 103_000_000);
+    assert_eq!(suite.balance(BOB, GOLD), 99_000_000);
+}
+
+#[test]
+fn crank_moves_past_unfillable_orders() {
+    let mut suite = Suite::new(pair_config(RateSource::Fixed {}));
+    suite.set_rate("1").unwrap();
+
+    // The OM reserve holds 100 OM, so Alice's order cannot fill at 2 while Bob's can
+    suite.place_order(ALICE, 60_000_000, "1");
+    suite.place_order(BOB, 1_000_000, "1");
+    suite
+        .execute(
+            GUARDIAN,
+            &ExecuteMsg::EmergencyPause {
+                operation: Some(Operation::Swap),
+            },
+            &[],
+        )
+        .unwrap();
+
+    // Paused swaps hold back the fills a rate update would trigger
+    suite.set_rate("2").unwrap();
+    assert_eq!(suite.order_book().len(), 2);
+    suite
+        .execute(
+            GUARDIAN,
+            &ExecuteMsg::EmergencyUnpause {
+                operation: Some(Operation::Swap),
+            },
+            &[],
+        )
+        .unwrap();
+
+    // The first call only gets to Alice's order, the next one carries on to Bob's
+    suite.execute_orders(1);
+    assert_eq!(suite.order_book().len(), 2);
+    suite.execute_orders(1);
+    let book: Vec<Addr> = suite
+        .order_book()
+        .into_iter()
+        .map(|order| order.owner)
+        .collect();
+    assert_eq!(book, vec![Addr::unchecked(ALICE)]);
+    assert_eq!(suite.balance(BOB, OM), 102_000_000);
+}
+
+#[test]
+fn orders_above_volume_cap_are_refused() {
+    let mut pair = pair_config(RateSource::Fixed {});
+    pair.volume_limits = VolumeLimits {
+        per_address_daily: Some(Uint128::new(5_000_000)),
+        global_per_epoch: None,
+        epoch_seconds: 0,
+    };
+    let mut suite = Suite::new(pair);
+    suite.set_rate("1").unwrap();
+
+    let err = suite
+        .execute(
+            ALICE,
+            &ExecuteMsg::PlaceLimitOrder {
+                min_rate: Decimal::one(),
+                expires_at: None,
+            },
+            &coins(6_000_000, GOLD),
+        )
+        .unwrap_err();
+    assert!(matches!(err, ContractError::InvalidOrder { .. }));
+}
+
+#[test]
+fn only_the_owner_cancels_an_order() {
+    let mut suite = Suite::new(pair_config(RateSource::Fixed {}));
+    suite.set_rate("1").unwrap();
+    let id = suite.place_order(ALICE, 1_000_000, "2");
+
+    let cancel = ExecuteMsg::CancelOrder { id };
+    let err = suite.execute(BOB, &cancel, &[]).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    suite.execute(ALICE, &cancel, &[]).unwrap();
+    assert!(suite.order_book().is_empty());
+    assert_eq!(suite.balance(ALICE, GOLD), 100_000_000);
+}
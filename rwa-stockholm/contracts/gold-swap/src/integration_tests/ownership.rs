@@ -0,0 +1,86 @@
+use rwa_stockholm_packages::ownership::{OwnershipResponse, Role, RoleHolder};
+
+use super::*;
+
+const NEW_ADMIN: &str = "new_admin";
+const RATE_SETTER: &str = "rate_setter";
+
+fn ownership(suite: &Suite) -> OwnershipResponse {
+    suite.query(&QueryMsg::Ownership {})
+}
+
+#[test]
+fn admin_changes_hands_in_two_steps() {
+    let mut suite = Suite::new(pair_config(RateSource::Fixed {}));
+    let propose = ExecuteMsg::ProposeNewAdmin {
+        new_admin: NEW_ADMIN.to_string(),
+    };
+
+    let err = suite.execute(ALICE, &propose, &[]).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    suite.execute(ADMIN, &propose, &[]).unwrap();
+    // The old admin keeps control until the transfer is accepted
+    let response = ownership(&suite);
+    assert_eq!(response.admin, ADMIN);
+    assert_eq!(response.pending_admin, Some(Addr::unchecked(NEW_ADMIN)));
+
+    let err = suite
+        .execute(ALICE, &ExecuteMsg::AcceptAdmin {}, &[])
+        .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    suite
+        .execute(NEW_ADMIN, &ExecuteMsg::AcceptAdmin {}, &[])
+        .unwrap();
+    let response = ownership(&suite);
+    assert_eq!(response.admin, NEW_ADMIN);
+    assert_eq!(response.pending_admin, None);
+
+    let err = suite.set_rate("2").unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+}
+
+#[test]
+fn rate_setter_only_sets_rates() {
+    let mut suite = Suite::new(pair_config(RateSource::Fixed {}));
+    let set_role = |address: Option<&str>| ExecuteMsg::SetRole {
+        role: Role::RateSetter,
+        address: address.map(str::to_string),
+    };
+    let set_rate = ExecuteMsg::SetExchangeRate {
+        base_token: GOLD.to_string(),
+        rate: Decimal::percent(200),
+    };
+
+    let err = suite
+        .execute(ALICE, &set_role(Some(ALICE)), &[])
+        .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    suite
+        .execute(ADMIN, &set_role(Some(RATE_SETTER)), &[])
+        .unwrap();
+    assert_eq!(
+        ownership(&suite).roles,
+        vec![RoleHolder {
+            role: Role::RateSetter,
+            address: Addr::unchecked(RATE_SETTER),
+        }]
+    );
+
+    suite.execute(RATE_SETTER, &set_rate, &[]).unwrap();
+    // The admin can act in every role itself
+    suite.set_rate("2.1").unwrap();
+    let err = suite
+        .execute(
+            RATE_SETTER,
+            &ExecuteMsg::WithdrawFees {
+                to: RATE_SETTER.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    suite.execute(ADMIN, &set_role(None), &[]).unwrap();
+    let err = suite.execute(RATE_SETTER, &set_rate, &[]).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+}
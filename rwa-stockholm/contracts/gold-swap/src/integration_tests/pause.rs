@@ -0,0 +1,112 @@
+use rwa_stockholm_packages::msg::gold_swap::ConfigResponse;
+use rwa_stockholm_packages::ownership::Role;
+use rwa_stockholm_packages::pause::Operation;
+
+use super::*;
+
+const PAUSER: &str = "pauser";
+
+fn paused_swaps_error() -> ContractError {
+    ContractError::OperationPaused {
+        operation: "swap".to_string(),
+    }
+}
+
+fn suite_with_pauser() -> Suite {
+    let mut suite = Suite::new(pair_config(RateSource::Fixed {}));
+    suite.set_rate("1").unwrap();
+    suite
+        .execute(
+            ADMIN,
+            &ExecuteMsg::SetRole {
+                role: Role::Pauser,
+                address: Some(PAUSER.to_string()),
+            },
+            &[],
+        )
+        .unwrap();
+    suite
+}
+
+#[test]
+fn pauser_cannot_lift_a_guardian_halt() {
+    let mut suite = suite_with_pauser();
+    suite
+        .execute(
+            GUARDIAN,
+            &ExecuteMsg::EmergencyPause {
+                operation: Some(Operation::Swap),
+            },
+            &[],
+        )
+        .unwrap();
+
+    let err = suite
+        .execute(PAUSER, &ExecuteMsg::Unpause { base_token: None }, &[])
+        .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    // Unpausing the pair itself leaves the guardian's halt in place
+    suite
+        .execute(
+            PAUSER,
+            &ExecuteMsg::Unpause {
+                base_token: Some(GOLD.to_string()),
+            },
+            &[],
+        )
+        .unwrap();
+    let err = suite.swap(ALICE, coin(1_000_000, GOLD)).unwrap_err();
+    assert_eq!(err, paused_swaps_error());
+    let config: ConfigResponse = suite.query(&QueryMsg::Config {});
+    assert!(config.paused);
+
+    suite
+        .execute(
+            GUARDIAN,
+            &ExecuteMsg::EmergencyUnpause {
+                operation: Some(Operation::Swap),
+            },
+            &[],
+        )
+        .unwrap();
+    suite.swap(ALICE, coin(1_000_000, GOLD)).unwrap();
+}
+
+#[test]
+fn pausing_every_pair_is_left_to_the_guardian() {
+    let mut suite = suite_with_pauser();
+
+    let pause_all = ExecuteMsg::Pause { base_token: None };
+    let err = suite.execute(PAUSER, &pause_all, &[]).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    suite.execute(GUARDIAN, &pause_all, &[]).unwrap();
+    let err = suite.swap(ALICE, coin(1_000_000, GOLD)).unwrap_err();
+    assert_eq!(err, paused_swaps_error());
+}
+
+#[test]
+fn pauser_pauses_a_single_pair() {
+    let mut suite = suite_with_pauser();
+
+    suite
+        .execute(
+            PAUSER,
+            &ExecuteMsg::Pause {
+                base_token: Some(GOLD.to_string()),
+            },
+            &[],
+        )
+        .unwrap();
+    suite.swap(ALICE, coin(1_000_000, GOLD)).unwrap_err();
+
+    suite
+        .execute(
+            PAUSER,
+            &ExecuteMsg::Unpause {
+                base_token: Some(GOLD.to_string()),
+            },
+            &[],
+        )
+        .unwrap();
+    suite.swap(ALICE, coin(1_000_000, GOLD)).unwrap();
+}
@@ -0,0 +1,117 @@
+use cosmwasm_std::Timestamp;
+use rwa_stockholm_packages::msg::gold_swap::{ReserveBacking, ReserveStatusResponse};
+
+use super::*;
+
+const AUDITOR: &str = "auditor";
+// Admin, Alice and Bob hold 1,200 gold tokens between them
+const GOLD_SUPPLY: u128 = 1_200_000_000;
+
+fn backed_suite() -> Suite {
+    let mut suite = Suite::new(pair_config(RateSource::Fixed {}));
+    suite.set_rate("1").unwrap();
+    suite
+        .execute(
+            ADMIN,
+            &ExecuteMsg::AddAuditor {
+                address: AUDITOR.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    suite
+        .execute(
+            ADMIN,
+            &ExecuteMsg::SetReserveBacking {
+                base_token: GOLD.to_string(),
+                backing: Some(ReserveBacking {
+                    tokens_per_ounce: Decimal::one(),
+                    max_attestation_age_seconds: Some(86_400),
+                }),
+            },
+            &[],
+        )
+        .unwrap();
+    suite
+}
+
+fn attest(
+    suite: &mut Suite,
+    sender: &str,
+    ounces: u64,
+    reported_at: Timestamp,
+) -> Result<AppResponse, ContractError> {
+    suite.execute(
+        sender,
+        &ExecuteMsg::PostAttestation {
+            base_token: GOLD.to_string(),
+            ounces: Decimal::from_atomics(ounces, 0).unwrap(),
+            report_hash: "a1b2c3".to_string(),
+            reported_at,
+        },
+        &[],
+    )
+}
+
+#[test]
+fn only_auditors_post_attestations_in_order() {
+    let mut suite = backed_suite();
+    let now = suite.app.block_info().time;
+
+    let err = attest(&mut suite, ALICE, 1_200, now).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    let err = attest(&mut suite, AUDITOR, 1_200, now.plus_seconds(1)).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidAttestation { .. }));
+
+    attest(&mut suite, AUDITOR, 1_200, now).unwrap();
+    let err = attest(&mut suite, AUDITOR, 1_200, now.minus_seconds(1)).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidAttestation { .. }));
+}
+
+#[test]
+fn buying_needs_attested_reserves_for_the_whole_supply() {
+    let mut suite = backed_suite();
+
+    let err = suite.swap(BOB, coin(1_000_000, OM)).unwrap_err();
+    assert!(matches!(err, ContractError::AttestationMissing { .. }));
+
+    let now = suite.app.block_info().time;
+    attest(&mut suite, AUDITOR, 1_199, now).unwrap();
+    let err = suite.swap(BOB, coin(1_000_000, OM)).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::UnbackedSupply {
+            token: GOLD.to_string(),
+            supply: Uint128::new(GOLD_SUPPLY),
+            backed: Uint128::new(1_199_000_000),
+        }
+    );
+    // Selling the base token is never held back
+    suite.swap(ALICE, coin(1_000_000, GOLD)).unwrap();
+
+    attest(&mut suite, AUDITOR, 1_200, now).unwrap();
+    suite.swap(BOB, coin(1_000_000, OM)).unwrap();
+    let status: ReserveStatusResponse = suite.query(&QueryMsg::ReserveStatus {});
+    assert!(status.pairs[0].backed);
+    assert_eq!(status.pairs[0].backed_supply, Uint128::new(GOLD_SUPPLY));
+}
+
+#[test]
+fn stale_attestation_stops_buying() {
+    let mut suite = backed_suite();
+    let now = suite.app.block_info().time;
+    attest(&mut suite, AUDITOR, 1_200, now).unwrap();
+
+    suite.advance(86_401);
+    let err = suite.swap(BOB, coin(1_000_000, OM)).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::StaleAttestation {
+            token: GOLD.to_string(),
+            age_seconds: 86_401,
+            max_age_seconds: 86_400,
+        }
+    );
+    let status: ReserveStatusResponse = suite.query(&QueryMsg::ReserveStatus {});
+    assert!(!status.pairs[0].backed);
+}
@@ -0,0 +1,90 @@
+use cosmwasm_std::Timestamp;
+use rwa_stockholm_packages::msg::gold_swap::{RateHistoryResponse, RatePoint, TwapResponse};
+
+use super::*;
+
+fn twap(suite: &Suite, window_seconds: u64) -> TwapResponse {
+    suite.query(&QueryMsg::Twap {
+        base_token: GOLD.to_string(),
+        window_seconds,
+    })
+}
+
+fn history(suite: &Suite) -> Vec<RatePoint> {
+    let response: RateHistoryResponse = suite.query(&QueryMsg::RateHistory {
+        base_token: GOLD.to_string(),
+        start: None,
+        end: None,
+        limit: None,
+    });
+    response.rates
+}
+
+#[test]
+fn twap_weighs_each_rate_by_how_long_it_held() {
+    let mut suite = Suite::new(pair_config(RateSource::Fixed {}));
+    // History is kept by the second
+    let start = Timestamp::from_seconds(suite.app.block_info().time.seconds());
+    suite.set_rate("2").unwrap();
+    suite.advance(3000);
+    suite.set_rate("4").unwrap();
+    suite.advance(1000);
+
+    assert_eq!(
+        history(&suite),
+        vec![
+            RatePoint {
+                timestamp: start,
+                rate: Decimal::percent(200),
+            },
+            RatePoint {
+                timestamp: start.plus_seconds(3000),
+                rate: Decimal::percent(400),
+            },
+        ]
+    );
+
+    // 2 for 3000s and 4 for 1000s
+    let response = twap(&suite, 4000);
+    assert_eq!(response.twap, Decimal::percent(250));
+    assert_eq!(response.observations, 2);
+    // A window opening mid-way takes the rate in effect at its start
+    assert_eq!(twap(&suite, 2000).twap, Decimal::percent(300));
+    // A window longer than the history only averages what was recorded
+    assert_eq!(twap(&suite, 10_000).twap, Decimal::percent(250));
+}
+
+#[test]
+fn twap_needs_a_recorded_rate() {
+    let suite = Suite::new(pair_config(RateSource::Fixed {}));
+
+    let err = suite
+        .app
+        .wrap()
+        .query_wasm_smart::<TwapResponse>(
+            suite.swap.clone(),
+            &QueryMsg::Twap {
+                base_token: GOLD.to_string(),
+                window_seconds: 3600,
+            },
+        )
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains(&ContractError::RateNotSet {}.to_string()));
+}
+
+#[test]
+fn oracle_rates_are_recorded_as_swaps_observe_them() {
+    let (mut suite, oracle) = oracle_suite("2");
+    suite.swap(ALICE, coin(1_000_000, GOLD)).unwrap();
+    suite.advance(30);
+    set_price(&mut suite.app, &oracle, "2.1", "0.001");
+    suite.swap(ALICE, coin(1_000_000, GOLD)).unwrap();
+
+    let rates: Vec<Decimal> = history(&suite)
+        .into_iter()
+        .map(|point| point.rate)
+        .collect();
+    assert_eq!(rates, vec![Decimal::percent(200), Decimal::percent(210)]);
+}
@@ -0,0 +1,59 @@
+use rwa_stockholm_packages::msg::gold_swap::{ReservesResponse, TokenAmount};
+
+use super::*;
+
+fn reserve(suite: &Suite, token: &str) -> Uint128 {
+    let response: ReservesResponse = suite.query(&QueryMsg::Reserves {});
+    response
+        .reserves
+        .into_iter()
+        .find(|reserve: &TokenAmount| reserve.token == token)
+        .map(|reserve| reserve.amount)
+        .unwrap_or_default()
+}
+
+#[test]
+fn swaps_the_reserve_cannot_cover_are_rejected_up_front() {
+    let mut suite = Suite::new(pair_config(RateSource::Fixed {}));
+    suite.set_rate("2").unwrap();
+
+    // 50 gold would pay out 100 OM, the whole reserve; one more unit is too much
+    suite.swap(ALICE, coin(50_000_000, GOLD)).unwrap();
+    assert_eq!(reserve(&suite, OM), Uint128::zero());
+    assert_eq!(reserve(&suite, GOLD), Uint128::new(150_000_000));
+    let err = suite.swap(ALICE, coin(1, GOLD)).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InsufficientReserve {
+            token: OM.to_string(),
+            requested: Uint128::new(2),
+            available: Uint128::zero(),
+        }
+    );
+
+    suite
+        .execute(ADMIN, &ExecuteMsg::FundReserve {}, &[coin(2, OM)])
+        .unwrap();
+    suite.swap(ALICE, coin(1, GOLD)).unwrap();
+}
+
+#[test]
+fn only_the_admin_withdraws_the_reserve() {
+    let mut suite = Suite::new(pair_config(RateSource::Fixed {}));
+    let withdraw = |amount| ExecuteMsg::WithdrawReserve {
+        token: OM.to_string(),
+        amount: Uint128::new(amount),
+        to: ADMIN.to_string(),
+    };
+
+    let err = suite.execute(ALICE, &withdraw(1), &[]).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    let err = suite
+        .execute(ADMIN, &withdraw(100_000_001), &[])
+        .unwrap_err();
+    assert!(matches!(err, ContractError::InsufficientReserve { .. }));
+
+    suite.execute(ADMIN, &withdraw(40_000_000), &[]).unwrap();
+    assert_eq!(reserve(&suite, OM), Uint128::new(60_000_000));
+    assert_eq!(suite.balance(ADMIN, OM), 940_000_000);
+}
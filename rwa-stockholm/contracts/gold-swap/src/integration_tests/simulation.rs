@@ -0,0 +1,55 @@
+use rwa_stockholm_packages::msg::gold_swap::{ReverseSimulationResponse, SimulationResponse};
+
+use super::*;
+
+fn fee_suite() -> Suite {
+    let mut pair = pair_config(RateSource::Fixed {});
+    pair.sell = SwapParams {
+        fee_bps: 30,
+        spread_bps: 20,
+    };
+    pair.swap_fee_bps = 10;
+    let mut suite = Suite::new(pair);
+    suite.set_rate("2345.67").unwrap();
+    suite
+}
+
+#[test]
+fn simulation_matches_swap_after_fees() {
+    let mut suite = fee_suite();
+
+    let simulation: SimulationResponse = suite.query(&QueryMsg::Simulation {
+        offer_asset: GOLD.to_string(),
+        ask_asset: None,
+        amount: Uint128::new(12_345),
+    });
+    suite.swap(ALICE, coin(12_345, GOLD)).unwrap();
+
+    let received = suite.balance(ALICE, OM) - 100_000_000;
+    assert_eq!(received, simulation.return_amount.u128());
+    // 0.012345 gold at 2345.67 is 28.957296 OM after rounding down. The spread, fee and
+    // protocol fee then come off in turn, each rounded up in the contract's favour.
+    assert_eq!(received, 28_783_869);
+}
+
+#[test]
+fn reverse_simulation_offer_is_the_smallest_that_reaches_the_ask() {
+    let suite = fee_suite();
+
+    let reverse: ReverseSimulationResponse = suite.query(&QueryMsg::ReverseSimulation {
+        ask_asset: OM.to_string(),
+        offer_asset: Some(GOLD.to_string()),
+        amount: Uint128::new(10_000_000),
+    });
+    let simulate = |amount: Uint128| -> Uint128 {
+        let simulation: SimulationResponse = suite.query(&QueryMsg::Simulation {
+            offer_asset: GOLD.to_string(),
+            ask_asset: None,
+            amount,
+        });
+        simulation.return_amount
+    };
+
+    assert!(simulate(reverse.offer_amount) >= Uint128::new(10_000_000));
+    assert!(simulate(reverse.offer_amount - Uint128::one()) < Uint128::new(10_000_000));
+}
@@ -0,0 +1,147 @@
+use rwa_stockholm_packages::msg::gold_swap::{
+    ExchangeRateResponse, PendingPairUpdate, PendingRateResponse,
+};
+
+use super::*;
+
+fn timelocked_suite() -> Suite {
+    let mut pair = pair_config(RateSource::Fixed {});
+    pair.rate_delay_seconds = 3600;
+    Suite::new(pair)
+}
+
+fn propose_rate(
+    suite: &mut Suite,
+    rate: &str,
+    delay_seconds: u64,
+) -> Result<AppResponse, ContractError> {
+    let effective_at = suite.app.block_info().time.plus_seconds(delay_seconds);
+    suite.execute(
+        ADMIN,
+        &ExecuteMsg::ProposeRate {
+            base_token: GOLD.to_string(),
+            rate: rate.parse().unwrap(),
+            effective_at,
+        },
+        &[],
+    )
+}
+
+fn rate(suite: &Suite) -> Decimal {
+    let response: ExchangeRateResponse = suite.query(&QueryMsg::ExchangeRate {
+        base_token: GOLD.to_string(),
+    });
+    response.rate
+}
+
+#[test]
+fn proposed_rate_waits_out_the_delay() {
+    let mut suite = timelocked_suite();
+
+    let err = propose_rate(&mut suite, "2", 3599).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::RateTimelocked {
+            delay_seconds: 3600
+        }
+    );
+    propose_rate(&mut suite, "2", 3600).unwrap();
+    let pending: PendingRateResponse = suite.query(&QueryMsg::PendingRate {});
+    assert_eq!(pending.rates.len(), 1);
+    assert_eq!(pending.rates[0].rate, Decimal::percent(200));
+
+    suite.advance(3599);
+    assert_eq!(rate(&suite), Decimal::zero());
+    suite.advance(1);
+    assert_eq!(rate(&suite), Decimal::percent(200));
+}
+
+#[test]
+fn guardian_cancels_a_pending_rate() {
+    let mut suite = timelocked_suite();
+    propose_rate(&mut suite, "2", 3600).unwrap();
+
+    let cancel = ExecuteMsg::CancelRate {
+        base_token: GOLD.to_string(),
+    };
+    let err = suite.execute(ADMIN, &cancel, &[]).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    suite.execute(GUARDIAN, &cancel, &[]).unwrap();
+
+    suite.advance(3600);
+    assert_eq!(rate(&suite), Decimal::zero());
+    let pending: PendingRateResponse = suite.query(&QueryMsg::PendingRate {});
+    assert!(pending.rates.is_empty());
+}
+
+#[test]
+fn shorter_rate_delay_waits_out_the_current_one() {
+    let mut suite = timelocked_suite();
+
+    let update = ExecuteMsg::UpdatePair {
+        base_token: GOLD.to_string(),
+        update: PairUpdate {
+            rate_delay_seconds: Some(0),
+            ..Default::default()
+        },
+    };
+    suite.execute(ADMIN, &update, &[]).unwrap();
+    let err = suite.set_rate("2").unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::RateTimelocked {
+            delay_seconds: 3600
+        }
+    );
+
+    let apply = ExecuteMsg::ApplyPairUpdate {
+        base_token: GOLD.to_string(),
+    };
+    let err = suite.execute(ADMIN, &apply, &[]).unwrap_err();
+    assert!(matches!(err, ContractError::PairUpdateNotDue { .. }));
+
+    suite.advance(3600);
+    suite.execute(ADMIN, &apply, &[]).unwrap();
+    suite.set_rate("2").unwrap();
+}
+
+#[test]
+fn guardian_cancels_a_pending_pair_update() {
+    let mut suite = timelocked_suite();
+    suite
+        .execute(
+            ADMIN,
+            &ExecuteMsg::UpdatePair {
+                base_token: GOLD.to_string(),
+                update: PairUpdate {
+                    rate_delay_seconds: Some(0),
+                    ..Default::default()
+                },
+            },
+            &[],
+        )
+        .unwrap();
+
+    let cancel = ExecuteMsg::CancelPairUpdate {
+        base_token: GOLD.to_string(),
+    };
+    let err = suite.execute(ADMIN, &cancel, &[]).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+    suite.execute(GUARDIAN, &cancel, &[]).unwrap();
+
+    let pending: Option<PendingPairUpdate> = suite.query(&QueryMsg::PendingPairUpdate {
+        base_token: GOLD.to_string(),
+    });
+    assert_eq!(pending, None);
+    suite.advance(3600);
+    let err = suite
+        .execute(
+            ADMIN,
+            &ExecuteMsg::ApplyPairUpdate {
+                base_token: GOLD.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert!(matches!(err, ContractError::NoPendingPairUpdate { .. }));
+}
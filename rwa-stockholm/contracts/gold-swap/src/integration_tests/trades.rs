@@ -0,0 +1,90 @@
+use cosmwasm_std::Timestamp;
+use rwa_stockholm_packages::msg::gold_swap::{Asset, Trade, TradesResponse};
+
+use super::*;
+
+fn native(denom: &str, amount: u128) -> Asset {
+    Asset {
+        info: AssetInfo::Native {
+            denom: denom.to_string(),
+        },
+        amount: Uint128::new(amount),
+    }
+}
+
+fn trades_by_user(suite: &Suite, user: &str, start_after: Option<u64>) -> Vec<Trade> {
+    let response: TradesResponse = suite.query(&QueryMsg::TradesByUser {
+        user: user.to_string(),
+        start_after,
+        limit: Some(2),
+    });
+    response.trades
+}
+
+#[test]
+fn every_swap_leaves_a_receipt() {
+    let mut pair = pair_config(RateSource::Fixed {});
+    pair.swap_fee_bps = 100;
+    let mut suite = Suite::new(pair);
+    suite.set_rate("2").unwrap();
+
+    let res = suite.swap(ALICE, coin(1_000_000, GOLD)).unwrap();
+    let trades = trades_by_user(&suite, ALICE, None);
+    let expected = Trade {
+        id: trades[0].id,
+        user: Addr::unchecked(ALICE),
+        base_token: GOLD.to_string(),
+        offer: native(GOLD, 1_000_000),
+        ask: native(OM, 1_980_000),
+        rate: Decimal::percent(200),
+        fee: native(OM, 20_000),
+        timestamp: suite.app.block_info().time,
+    };
+    assert_eq!(trades, vec![expected.clone()]);
+    let trade: Trade = suite.query(&QueryMsg::Trade { id: expected.id });
+    assert_eq!(trade, expected);
+
+    // Indexers read the same fields off a stable event
+    let event = res
+        .events
+        .iter()
+        .find(|event| event.ty == "wasm-swap")
+        .unwrap();
+    let attribute = |key: &str| {
+        event
+            .attributes
+            .iter()
+            .find(|attr| attr.key == key)
+            .map(|attr| attr.value.clone())
+    };
+    assert_eq!(attribute("trade_id"), Some(expected.id.to_string()));
+    assert_eq!(attribute("user"), Some(ALICE.to_string()));
+    assert_eq!(attribute("return_amount"), Some("1980000".to_string()));
+    assert_eq!(attribute("fee_amount"), Some("20000".to_string()));
+    assert_eq!(
+        attribute("timestamp"),
+        Some(expected.timestamp.seconds().to_string())
+    );
+}
+
+#[test]
+fn trades_by_user_pages_through_one_user_only() {
+    let mut suite = Suite::new(pair_config(RateSource::Fixed {}));
+    suite.set_rate("1").unwrap();
+    for sender in [ALICE, BOB, ALICE, ALICE] {
+        suite.swap(sender, coin(1_000, GOLD)).unwrap();
+        suite.advance(5);
+    }
+
+    let first_page = trades_by_user(&suite, ALICE, None);
+    assert_eq!(first_page.len(), 2);
+    let second_page = trades_by_user(&suite, ALICE, Some(first_page[1].id));
+    assert_eq!(second_page.len(), 1);
+
+    let trades: Vec<Trade> = first_page.into_iter().chain(second_page).collect();
+    assert!(trades.iter().all(|trade| trade.user == ALICE));
+    assert!(trades.windows(2).all(|pair| pair[0].id < pair[1].id));
+    let timestamps: Vec<Timestamp> = trades.iter().map(|trade| trade.timestamp).collect();
+    assert!(timestamps.windows(2).all(|pair| pair[0] < pair[1]));
+    assert_eq!(trades_by_user(&suite, BOB, None).len(), 1);
+}
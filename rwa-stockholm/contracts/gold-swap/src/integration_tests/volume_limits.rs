@@ -0,0 +1,125 @@
+use rwa_stockholm_packages::msg::gold_swap::RemainingAllowanceResponse;
+
+use super::*;
+
+fn capped_suite(volume_limits: VolumeLimits) -> Suite {
+    let mut pair = pair_config(RateSource::Fixed {});
+    pair.volume_limits = volume_limits;
+    let mut suite = Suite::new(pair);
+    suite.set_rate("1").unwrap();
+    suite
+}
+
+fn remaining(suite: &Suite, address: &str) -> RemainingAllowanceResponse {
+    suite.query(&QueryMsg::RemainingAllowance {
+        base_token: GOLD.to_string(),
+        address: address.to_string(),
+    })
+}
+
+#[test]
+fn daily_cap_rolls_over_24_hours() {
+    let mut suite = capped_suite(VolumeLimits {
+        per_address_daily: Some(Uint128::new(5_000_000)),
+        global_per_epoch: None,
+        epoch_seconds: 0,
+    });
+
+    suite.swap(ALICE, coin(5_000_000, GOLD)).unwrap();
+    // Half a day later the cap is still used up
+    suite.advance(12 * 3600);
+    let err = suite.swap(ALICE, coin(1, GOLD)).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::VolumeLimitExceeded {
+            limit: "daily".to_string(),
+            remaining: Uint128::zero(),
+        }
+    );
+    // Bob's allowance is separate
+    suite.swap(BOB, coin(5_000_000, GOLD)).unwrap();
+
+    // A full day and one sub-bucket later the volume has left the window
+    suite.advance(13 * 3600);
+    suite.swap(ALICE, coin(5_000_000, GOLD)).unwrap();
+}
+
+#[test]
+fn epoch_cap_is_shared_by_all_addresses() {
+    let mut suite = capped_suite(VolumeLimits {
+        per_address_daily: None,
+        global_per_epoch: Some(Uint128::new(8_000_000)),
+        epoch_seconds: 3600,
+    });
+
+    suite.swap(ALICE, coin(5_000_000, GOLD)).unwrap();
+    // Buys count their base token side against the cap as well
+    let err = suite.swap(BOB, coin(4_000_000, OM)).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::VolumeLimitExceeded {
+            limit: "epoch".to_string(),
+            remaining: Uint128::new(3_000_000),
+        }
+    );
+    suite.swap(BOB, coin(3_000_000, OM)).unwrap();
+    assert_eq!(
+        remaining(&suite, ALICE).epoch_remaining,
+        Some(Uint128::zero())
+    );
+
+    suite.advance(3600 + 150);
+    assert_eq!(
+        remaining(&suite, ALICE).epoch_remaining,
+        Some(Uint128::new(8_000_000))
+    );
+}
+
+#[test]
+fn market_makers_are_uncapped() {
+    let mut suite = capped_suite(VolumeLimits {
+        per_address_daily: Some(Uint128::new(1_000_000)),
+        global_per_epoch: Some(Uint128::new(1_000_000)),
+        epoch_seconds: 3600,
+    });
+    suite
+        .execute(
+            ADMIN,
+            &ExecuteMsg::AddMarketMaker {
+                address: ALICE.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    suite.swap(ALICE, coin(5_000_000, GOLD)).unwrap();
+    let allowance = remaining(&suite, ALICE);
+    assert!(allowance.market_maker);
+    assert_eq!(allowance.daily_remaining, None);
+    // Market maker volume does not eat into everyone else's cap
+    suite.swap(BOB, coin(1_000_000, GOLD)).unwrap();
+}
+
+#[test]
+fn epoch_cap_needs_an_epoch_length() {
+    let mut suite = Suite::new(pair_config(RateSource::Fixed {}));
+
+    let err = suite
+        .execute(
+            ADMIN,
+            &ExecuteMsg::UpdatePair {
+                base_token: GOLD.to_string(),
+                update: PairUpdate {
+                    volume_limits: Some(VolumeLimits {
+                        per_address_daily: None,
+                        global_per_epoch: Some(Uint128::new(1_000_000)),
+                        epoch_seconds: 0,
+                    }),
+                    ..Default::default()
+                },
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert!(matches!(err, ContractError::InvalidVolumeLimits { .. }));
+}
@@ -0,0 +1,18 @@
+pub mod circuit_breaker;
+pub mod contract;
+pub mod dca;
+pub mod feeders;
+pub mod history;
+#[cfg(test)]
+mod integration_tests;
+pub mod limits;
+pub mod math;
+pub mod oracle;
+pub mod orders;
+pub mod proof_of_reserves;
+pub mod state;
+pub mod swap;
+pub mod timelock;
+pub mod trades;
+
+pub use rwa_stockholm_packages::ContractError;
@@ -0,0 +1,153 @@
+use cosmwasm_std::{Addr, Env, Storage, Uint128};
+use rwa_stockholm_packages::msg::gold_swap::VolumeLimits;
+
+use crate::state::{Pair, VolumeBucket, ADDRESS_VOLUME, MARKET_MAKERS, PAIR_VOLUME};
+use crate::ContractError;
+
+const DAY_SECONDS: u64 = 86_400;
+// Sub-buckets per rolling window. Every sub-bucket overlapping the window counts in full, so a
+// cap is never exceeded in any span of the window's length, at the cost of volume being
+// released up to one sub-bucket late.
+const BUCKETS: u64 = 24;
+
+/// What is left of each cap in the current windows; `None` means uncapped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Allowance {
+    pub daily: Option<Uint128>,
+    pub epoch: Option<Uint128>,
+}
+
+pub fn validate_volume_limits(limits: &VolumeLimits) -> Result<(), ContractError> {
+    if limits.global_per_epoch.is_some() && limits.epoch_seconds == 0 {
+        return Err(ContractError::InvalidVolumeLimits {
+            reason: "epoch_seconds must be positive when global_per_epoch is set".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Remaining allowance of `address` on `pair`. Market makers are uncapped.
+pub fn remaining_allowance(
+    storage: &dyn Storage,
+    env: &Env,
+    pair: &Pair,
+    address: &Addr,
+) -> Result<Allowance, ContractError> {
+    if MARKET_MAKERS.has(storage, address) {
+        return Ok(Allowance {
+            daily: None,
+            epoch: None,
+        });
+    }
+
+    let now = env.block.time.seconds();
+    let limits = &pair.volume_limits;
+    let daily = match limits.per_address_daily {
+        Some(limit) => {
+            let buckets = ADDRESS_VOLUME
+                .may_load(storage, (pair.base_token.as_str(), address))?
+                .unwrap_or_default();
+            Some(limit.saturating_sub(rolling_volume(&buckets, now, DAY_SECONDS)))
+        }
+        None => None,
+    };
+    let epoch = match limits.global_per_epoch {
+        Some(limit) => {
+            let buckets = PAIR_VOLUME
+                .may_load(storage, pair.base_token.as_str())?
+                .unwrap_or_default();
+            Some(limit.saturating_sub(rolling_volume(&buckets, now, limits.epoch_seconds)))
+        }
+        None => None,
+    };
+
+    Ok(Allowance { daily, epoch })
+}
+
+/// Counts a swap of `base_volume` against `address`'s daily cap and the pair's epoch cap, or
+/// fails with the allowance left under whichever cap it would break. Volume is only tracked
+/// while the cap it counts against is set.
+pub fn record_volume(
+    storage: &mut dyn Storage,
+    env: &Env,
+    pair: &Pair,
+    address: &Addr,
+    base_volume: Uint128,
+) -> Result<(), ContractError> {
+    if MARKET_MAKERS.has(storage, address) {
+        return Ok(());
+    }
+
+    let allowance = remaining_allowance(storage, env, pair, address)?;
+    for (limit, remaining) in [("daily", allowance.daily), ("epoch", allowance.epoch)] {
+        if let Some(remaining) = remaining {
+            if base_volume > remaining {
+                return Err(ContractError::VolumeLimitExceeded {
+                    limit: limit.to_string(),
+                    remaining,
+                });
+            }
+        }
+    }
+
+    let now = env.block.time.seconds();
+    if allowance.daily.is_some() {
+        let key = (pair.base_token.as_str(), address);
+        let buckets = ADDRESS_VOLUME.may_load(storage, key)?.unwrap_or_default();
+        ADDRESS_VOLUME.save(storage, key, &add(buckets, now, DAY_SECONDS, base_volume)?)?;
+    }
+    if allowance.epoch.is_some() {
+        let key = pair.base_token.as_str();
+        let buckets = PAIR_VOLUME.may_load(storage, key)?.unwrap_or_default();
+        PAIR_VOLUME.save(
+            storage,
+            key,
+            &add(buckets, now, pair.volume_limits.epoch_seconds, base_volume)?,
+        )?;
+    }
+
+    Ok(())
+}
+
+// Volume swapped in the `window_seconds` ending at `now`
+fn rolling_volume(buckets: &[VolumeBucket], now: u64, window_seconds: u64) -> Uint128 {
+    let bucket_seconds = bucket_seconds(window_seconds);
+    buckets
+        .iter()
+        .filter(|bucket| in_window(bucket, bucket_seconds, now, window_seconds))
+        .map(|bucket| bucket.volume)
+        .sum()
+}
+
+// Adds `amount` to the sub-bucket holding `now` and drops the ones that left the window
+fn add(
+    buckets: Vec<VolumeBucket>,
+    now: u64,
+    window_seconds: u64,
+    amount: Uint128,
+) -> Result<Vec<VolumeBucket>, ContractError> {
+    let bucket_seconds = bucket_seconds(window_seconds);
+    let start = now - now % bucket_seconds;
+    let mut buckets: Vec<_> = buckets
+        .into_iter()
+        .filter(|bucket| in_window(bucket, bucket_seconds, now, window_seconds))
+        .collect();
+    match buckets.last_mut() {
+        Some(bucket) if bucket.start == start => {
+            bucket.volume = bucket.volume.checked_add(amount)?
+        }
+        _ => buckets.push(VolumeBucket {
+            start,
+            volume: amount,
+        }),
+    }
+    Ok(buckets)
+}
+
+fn in_window(bucket: &VolumeBucket, bucket_seconds: u64, now: u64, window_seconds: u64) -> bool {
+    bucket.start + bucket_seconds > now.saturating_sub(window_seconds)
+}
+
+fn bucket_seconds(window_seconds: u64) -> u64 {
+    window_seconds.div_ceil(BUCKETS)
+}
@@ -0,0 +1,222 @@
+use cosmwasm_std::{Decimal, Uint128, Uint256};
+use rwa_stockholm_packages::msg::gold_swap::SwapParams;
+
+use crate::ContractError;
+
+/// `Decimal` always carries 18 fractional digits.
+const DECIMAL_FRACTIONAL_DIGITS: u32 = 18;
+
+/// Most decimals a pair's tokens may have, which keeps every conversion within `Uint256`.
+pub const MAX_TOKEN_DECIMALS: u8 = 18;
+
+const BPS_DENOMINATOR: u128 = 10_000;
+
+/// Highest share of a DCA swap a pair may pay the keeper that runs it, 1%.
+pub const MAX_KEEPER_TIP_BPS: u16 = 100;
+
+/// Direction to round a division that does not come out even.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    Down,
+    Up,
+}
+
+/// Breakdown of a swap: what the user receives and what was held back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SwapOutcome {
+    pub return_amount: Uint128,
+    pub spread_amount: Uint128,
+    pub fee_amount: Uint128,
+    pub protocol_fee_amount: Uint128,
+}
+
+/// Converts `amount` base units of a token with `from_decimals` into base units of a token
+/// with `to_decimals`, at `rate` whole output tokens per whole input token.
+///
+/// Swaps round down, toward the contract: the user never receives more than the rate allows
+/// and any remainder stays in the contract. Reverse simulations round up for the same reason.
+pub fn convert(
+    amount: Uint128,
+    rate: Decimal,
+    from_decimals: u8,
+    to_decimals: u8,
+    rounding: Rounding,
+) -> Result<Uint128, ContractError> {
+    let numerator = Uint256::from(rate.atomics()).checked_mul(pow10(to_decimals as u32)?)?;
+    let denominator = pow10(DECIMAL_FRACTIONAL_DIGITS + from_decimals as u32)?;
+
+    mul_div(amount, numerator, denominator, rounding)
+}
+
+/// Same as [`convert`], but at the inverse of `rate`, i.e. `rate` whole input tokens per whole
+/// output token. `rate` must not be zero.
+pub fn convert_inverse(
+    amount: Uint128,
+    rate: Decimal,
+    from_decimals: u8,
+    to_decimals: u8,
+    rounding: Rounding,
+) -> Result<Uint128, ContractError> {
+    let numerator = pow10(DECIMAL_FRACTIONAL_DIGITS + to_decimals as u32)?;
+    let denominator = Uint256::from(rate.atomics()).checked_mul(pow10(from_decimals as u32)?)?;
+
+    mul_div(amount, numerator, denominator, rounding)
+}
+
+/// Takes the spread, the direction's fee and then the protocol `swap_fee_bps` out of `gross`.
+/// All deductions are rounded up, so `return_amount` is rounded down, toward the contract.
+pub fn apply_swap_params(gross: Uint128, params: &SwapParams, swap_fee_bps: u16) -> SwapOutcome {
+    let after_spread = deduct_bps(gross, params.spread_bps);
+    let after_fee = deduct_bps(after_spread, params.fee_bps);
+    let return_amount = deduct_bps(after_fee, swap_fee_bps);
+
+    SwapOutcome {
+        return_amount,
+        spread_amount: gross - after_spread,
+        fee_amount: after_spread - after_fee,
+        protocol_fee_amount: after_fee - return_amount,
+    }
+}
+
+/// Smallest gross amount for which [`apply_swap_params`] returns at least `return_amount`.
+///
+/// Each deduction rounds down and is undone here rounding up, which gives the least amount
+/// that survives that deduction, so undoing them in reverse order stays minimal.
+pub fn gross_for_return(
+    return_amount: Uint128,
+    params: &SwapParams,
+    swap_fee_bps: u16,
+) -> Result<Uint128, ContractError> {
+    let after_fee = add_back_bps(return_amount, swap_fee_bps)?;
+    let after_spread = add_back_bps(after_fee, params.fee_bps)?;
+    add_back_bps(after_spread, params.spread_bps)
+}
+
+pub fn validate_swap_params(params: &SwapParams, swap_fee_bps: u16) -> Result<(), ContractError> {
+    let total = params.fee_bps as u128 + params.spread_bps as u128 + swap_fee_bps as u128;
+    if total >= BPS_DENOMINATOR {
+        return Err(ContractError::InvalidSwapParams {
+            reason: "fee_bps + spread_bps + swap_fee_bps must be below 10000".to_string(),
+        });
+    }
+    Ok(())
+}
+
+pub fn validate_keeper_tip(keeper_tip_bps: u16) -> Result<(), ContractError> {
+    if keeper_tip_bps > MAX_KEEPER_TIP_BPS {
+        return Err(ContractError::InvalidSwapParams {
+            reason: format!("keeper_tip_bps must be at most {MAX_KEEPER_TIP_BPS}"),
+        });
+    }
+    Ok(())
+}
+
+/// `bps` basis points of `amount`, rounded down.
+pub fn bps_of_amount(amount: Uint128, bps: u16) -> Uint128 {
+    amount.multiply_ratio(bps as u128, BPS_DENOMINATOR)
+}
+
+/// `bps` basis points of `value`.
+pub fn bps_of(value: Decimal, bps: u16) -> Result<Decimal, ContractError> {
+    Ok(value.checked_mul(Decimal::from_ratio(bps, BPS_DENOMINATOR))?)
+}
+
+pub fn abs_diff(a: Decimal, b: Decimal) -> Decimal {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+fn deduct_bps(amount: Uint128, bps: u16) -> Uint128 {
+    amount.multiply_ratio(BPS_DENOMINATOR - bps as u128, BPS_DENOMINATOR)
+}
+
+fn add_back_bps(amount: Uint128, bps: u16) -> Result<Uint128, ContractError> {
+    mul_div(
+        amount,
+        Uint256::from(BPS_DENOMINATOR),
+        Uint256::from(BPS_DENOMINATOR - bps as u128),
+        Rounding::Up,
+    )
+}
+
+fn mul_div(
+    amount: Uint128,
+    numerator: Uint256,
+    denominator: Uint256,
+    rounding: Rounding,
+) -> Result<Uint128, ContractError> {
+    let product = Uint256::from(amount).checked_mul(numerator)?;
+    let mut quotient = product / denominator;
+    if rounding == Rounding::Up && quotient * denominator != product {
+        quotient += Uint256::from(1u8);
+    }
+
+    Ok(Uint128::try_from(quotient)?)
+}
+
+fn pow10(exp: u32) -> Result<Uint256, ContractError> {
+    Ok(Uint256::from(10u128).checked_pow(exp)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversions_round_in_the_requested_direction() {
+        let rate = Decimal::percent(150);
+        let amount = |amount| Uint128::new(amount);
+
+        assert_eq!(
+            convert(amount(1), rate, 6, 6, Rounding::Down),
+            Ok(amount(1))
+        );
+        assert_eq!(convert(amount(1), rate, 6, 6, Rounding::Up), Ok(amount(2)));
+        // An exact result is never rounded up
+        assert_eq!(convert(amount(2), rate, 6, 6, Rounding::Up), Ok(amount(3)));
+        // Scaling to fewer decimals rounds too
+        assert_eq!(
+            convert(amount(1_999_999), Decimal::one(), 6, 0, Rounding::Down),
+            Ok(amount(1))
+        );
+        assert_eq!(
+            convert(amount(1_000_001), Decimal::one(), 6, 0, Rounding::Up),
+            Ok(amount(2))
+        );
+
+        let rate = Decimal::percent(200);
+        assert_eq!(
+            convert_inverse(amount(3), rate, 6, 6, Rounding::Down),
+            Ok(amount(1))
+        );
+        assert_eq!(
+            convert_inverse(amount(3), rate, 6, 6, Rounding::Up),
+            Ok(amount(2))
+        );
+    }
+
+    #[test]
+    fn oversized_decimals_are_an_error() {
+        let result = convert(Uint128::one(), Decimal::one(), 6, 80, Rounding::Down);
+        assert!(matches!(result, Err(ContractError::Overflow(_))));
+    }
+
+    #[test]
+    fn gross_for_return_is_minimal() {
+        let params = SwapParams {
+            fee_bps: 30,
+            spread_bps: 20,
+        };
+        for return_amount in [1u128, 7, 999, 28_783_869, 1_000_000_007] {
+            let return_amount = Uint128::new(return_amount);
+            let gross = gross_for_return(return_amount, &params, 10).unwrap();
+
+            assert!(apply_swap_params(gross, &params, 10).return_amount >= return_amount);
+            let short = apply_swap_params(gross - Uint128::one(), &params, 10);
+            assert!(short.return_amount < return_amount);
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use cosmwasm_std::{Api, Decimal, Deps, Env, QuerierWrapper, StdResult, Timestamp};
+use rwa_stockholm_packages::msg::gold_swap::RateSource;
+use rwa_stockholm_packages::msg::oracle::{PriceResponse, QueryMsg as OracleQueryMsg};
+
+use crate::feeders::round_price;
+use crate::math::bps_of;
+use crate::state::Pair;
+use crate::timelock::fixed_rate;
+use crate::ContractError;
+
+/// The rate the pair's swaps would use right now, as reported by its source, without any checks.
+/// In feeder mode the quorum is not applied, and the rate is zero if nobody has submitted.
+pub fn latest_rate(
+    deps: Deps,
+    env: &Env,
+    pair: &Pair,
+) -> Result<(Decimal, Option<Timestamp>), ContractError> {
+    match &pair.rate_source {
+        RateSource::Fixed {} => Ok(fixed_rate(deps.storage, env, pair)?),
+        RateSource::Oracle {
+            oracle, pair: feed, ..
+        } => {
+            let price = query_price(&deps.querier, oracle, feed)?;
+            Ok((price.price, Some(price.publish_time)))
+        }
+        RateSource::Feeders {
+            round_seconds,
+            max_deviation_bps,
+            ..
+        } => Ok(
+            match round_price(
+                deps.storage,
+                env,
+                pair.base_token.as_str(),
+                *round_seconds,
+                *max_deviation_bps,
+            )? {
+                Some(round) => (round.price, Some(round.latest)),
+                None => (Decimal::zero(), None),
+            },
+        ),
+    }
+}
+
+/// The rate to swap the pair at. In oracle mode the price must be no older than
+/// `max_staleness_seconds` and its confidence band no wider than `max_confidence_bps` of it.
+/// In feeder mode at least `quorum` submissions must survive the outlier filter.
+pub fn current_rate(deps: Deps, env: &Env, pair: &Pair) -> Result<Decimal, ContractError> {
+    match &pair.rate_source {
+        RateSource::Fixed {} => Ok(fixed_rate(deps.storage, env, pair)?.0),
+        RateSource::Oracle {
+            oracle,
+            pair: feed,
+            max_staleness_seconds,
+            max_confidence_bps,
+        } => {
+            let price = query_price(&deps.querier, oracle, feed)?;
+
+            let age_seconds = env
+                .block
+                .time
+                .seconds()
+                .saturating_sub(price.publish_time.seconds());
+            if age_seconds > *max_staleness_seconds {
+                return Err(ContractError::StalePrice {
+                    age_seconds,
+                    max_staleness_seconds: *max_staleness_seconds,
+                });
+            }
+
+            if price.confidence > bps_of(price.price, *max_confidence_bps)? {
+                return Err(ContractError::PriceUncertain {
+                    price: price.price,
+                    confidence: price.confidence,
+                });
+            }
+
+            Ok(price.price)
+        }
+        RateSource::Feeders {
+            quorum,
+            round_seconds,
+            max_deviation_bps,
+        } => {
+            let round = round_price(
+                deps.storage,
+                env,
+                pair.base_token.as_str(),
+                *round_seconds,
+                *max_deviation_bps,
+            )?;
+            let submissions = round.map_or(0, |round| round.submissions);
+            match round {
+                Some(round) if submissions >= *quorum => Ok(round.price),
+                _ => Err(ContractError::QuorumNotMet {
+                    submissions,
+                    quorum: *quorum,
+                }),
+            }
+        }
+    }
+}
+
+fn query_price(querier: &QuerierWrapper, oracle: &str, feed: &str) -> StdResult<PriceResponse> {
+    querier.query_wasm_smart(
+        oracle,
+        &OracleQueryMsg::Price {
+            pair: feed.to_string(),
+        },
+    )
+}
+
+/// Validates the oracle address, storing it in its canonical form, and the feeder settings.
+pub fn validate_rate_source(
+    api: &dyn Api,
+    source: RateSource,
+) -> Result<RateSource, ContractError> {
+    Ok(match source {
+        RateSource::Fixed {} => RateSource::Fixed {},
+        RateSource::Oracle {
+            oracle,
+            pair,
+            max_staleness_seconds,
+            max_confidence_bps,
+        } => RateSource::Oracle {
+            oracle: api.addr_validate(&oracle)?.to_string(),
+            pair,
+            max_staleness_seconds,
+            max_confidence_bps,
+        },
+        RateSource::Feeders { quorum: 0, .. } => {
+            return Err(ContractError::InvalidRateSource {
+                reason: "quorum must be at least 1".to_string(),
+            })
+        }
+        feeders @ RateSource::Feeders { .. } => feeders,
+    })
+}
@@ -0,0 +1,112 @@
+use cosmwasm_std::{Addr, Decimal, Empty, Order, StdResult, Storage};
+use cw_storage_plus::Bound;
+use rwa_stockholm_packages::msg::gold_swap::LimitOrder;
+
+use crate::state::{ORDERS, ORDER_BOOK, ORDER_COUNT, ORDER_CURSORS, USER_ORDERS};
+
+/// Opens `order` under the next order id and returns it with the id filled in.
+pub fn place_order(storage: &mut dyn Storage, mut order: LimitOrder) -> StdResult<LimitOrder> {
+    let id = ORDER_COUNT.may_load(storage)?.unwrap_or_default() + 1;
+    ORDER_COUNT.save(storage, &id)?;
+
+    order.id = id;
+    ORDERS.save(storage, id, &order)?;
+    ORDER_BOOK.save(storage, book_key(&order), &Empty {})?;
+    USER_ORDERS.save(storage, (&order.owner, id), &Empty {})?;
+    Ok(order)
+}
+
+/// Takes a filled, cancelled or expired order out of the book.
+pub fn remove_order(storage: &mut dyn Storage, order: &LimitOrder) {
+    ORDERS.remove(storage, order.id);
+    ORDER_BOOK.remove(storage, book_key(order));
+    USER_ORDERS.remove(storage, (&order.owner, order.id));
+}
+
+/// Open orders on the pair by `min_rate`, lowest first and oldest first within a rate, starting
+/// after `start_after` and stopping past `max_rate`.
+pub fn order_book(
+    storage: &dyn Storage,
+    base_token: &str,
+    start_after: Option<&LimitOrder>,
+    max_rate: Option<Decimal>,
+    limit: usize,
+) -> StdResult<Vec<LimitOrder>> {
+    let min =
+        start_after.map(|order| Bound::exclusive((order.min_rate.atomics().u128(), order.id)));
+    let max = max_rate.map(|rate| Bound::inclusive((rate.atomics().u128(), u64::MAX)));
+    ORDER_BOOK
+        .sub_prefix(base_token)
+        .keys(storage, min, max, Order::Ascending)
+        .take(limit)
+        .map(|key| ORDERS.load(storage, key?.1))
+        .collect()
+}
+
+/// Up to `limit` orders at or below `max_rate` for the crank to look at next. The crank picks up
+/// after the last order it looked at and wraps around to the lowest `min_rate`, so orders that
+/// cannot fill yet do not hold up the rest of the book.
+pub fn next_crank_orders(
+    storage: &mut dyn Storage,
+    base_token: &str,
+    max_rate: Decimal,
+    limit: usize,
+) -> StdResult<Vec<LimitOrder>> {
+    let book = ORDER_BOOK.sub_prefix(base_token);
+    let max = (max_rate.atomics().u128(), u64::MAX);
+    let cursor = ORDER_CURSORS.may_load(storage, base_token)?;
+
+    let mut keys = book
+        .keys(
+            storage,
+            cursor.map(Bound::exclusive),
+            Some(Bound::inclusive(max)),
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+    if let Some(cursor) = cursor {
+        let wrapped = book
+            .keys(
+                storage,
+                None,
+                Some(Bound::inclusive(cursor.min(max))),
+                Order::Ascending,
+            )
+            .take(limit - keys.len())
+            .collect::<StdResult<Vec<_>>>()?;
+        keys.extend(wrapped);
+    }
+
+    match keys.last() {
+        Some(last) => ORDER_CURSORS.save(storage, base_token, last)?,
+        None => ORDER_CURSORS.remove(storage, base_token),
+    }
+    keys.into_iter()
+        .map(|(_, id)| ORDERS.load(storage, id))
+        .collect()
+}
+
+/// Open orders of `user` with an id above `start_after`, oldest first.
+pub fn orders_by_user(
+    storage: &dyn Storage,
+    user: &Addr,
+    start_after: Option<u64>,
+    limit: usize,
+) -> StdResult<Vec<LimitOrder>> {
+    let start = start_after.map(Bound::exclusive);
+    USER_ORDERS
+        .prefix(user)
+        .keys(storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|id| ORDERS.load(storage, id?))
+        .collect()
+}
+
+fn book_key(order: &LimitOrder) -> (&str, u128, u64) {
+    (
+        order.base_token.as_str(),
+        order.min_rate.atomics().u128(),
+        order.id,
+    )
+}
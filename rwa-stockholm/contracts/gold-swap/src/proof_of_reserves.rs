@@ -0,0 +1,96 @@
+use cosmwasm_std::{Deps, Env, QuerierWrapper, StdResult, Uint128};
+use cw20::{Cw20QueryMsg, TokenInfoResponse};
+use rwa_stockholm_packages::msg::gold_swap::{AssetInfo, PairReserveStatus, ReserveBacking};
+
+use crate::state::{Pair, ATTESTATIONS};
+use crate::ContractError;
+
+/// How the pair's base token supply compares to the latest attestation of its vault holdings.
+pub fn reserve_status(
+    deps: Deps,
+    env: &Env,
+    pair: &Pair,
+    backing: &ReserveBacking,
+) -> Result<PairReserveStatus, ContractError> {
+    let attestation = ATTESTATIONS.may_load(deps.storage, pair.base_token.as_str())?;
+    let circulating_supply = circulating_supply(&deps.querier, &pair.base_token)?;
+    let backed_supply = match &attestation {
+        Some(attestation) => {
+            // Whole tokens backed, scaled to the token's smallest unit
+            let whole = attestation.ounces.checked_mul(backing.tokens_per_ounce)?;
+            Uint128::new(10)
+                .checked_pow(pair.base_decimals.into())?
+                .checked_mul_floor(whole)?
+        }
+        None => Uint128::zero(),
+    };
+
+    let status = PairReserveStatus {
+        base_token: pair.base_token.as_str().to_string(),
+        attestation,
+        circulating_supply,
+        backed_supply,
+        backed: false,
+    };
+    Ok(PairReserveStatus {
+        backed: ensure_backed(env, backing, &status).is_ok(),
+        ..status
+    })
+}
+
+/// Fails unless the pair's latest attestation is recent enough and covers the base token's
+/// whole circulating supply.
+pub fn check_backing(
+    deps: Deps,
+    env: &Env,
+    pair: &Pair,
+    backing: &ReserveBacking,
+) -> Result<(), ContractError> {
+    ensure_backed(env, backing, &reserve_status(deps, env, pair, backing)?)
+}
+
+fn ensure_backed(
+    env: &Env,
+    backing: &ReserveBacking,
+    status: &PairReserveStatus,
+) -> Result<(), ContractError> {
+    let Some(attestation) = &status.attestation else {
+        return Err(ContractError::AttestationMissing {
+            token: status.base_token.clone(),
+        });
+    };
+    if let Some(max_age_seconds) = backing.max_attestation_age_seconds {
+        let age_seconds = env
+            .block
+            .time
+            .seconds()
+            .saturating_sub(attestation.reported_at.seconds());
+        if age_seconds > max_age_seconds {
+            return Err(ContractError::StaleAttestation {
+                token: status.base_token.clone(),
+                age_seconds,
+                max_age_seconds,
+            });
+        }
+    }
+    if status.circulating_supply > status.backed_supply {
+        return Err(ContractError::UnbackedSupply {
+            token: status.base_token.clone(),
+            supply: status.circulating_supply,
+            backed: status.backed_supply,
+        });
+    }
+    Ok(())
+}
+
+// Total supply of a CW20 from its TokenInfo, or of a native denom from the bank module
+fn circulating_supply(querier: &QuerierWrapper, asset: &AssetInfo) -> StdResult<Uint128> {
+    match asset {
+        AssetInfo::Cw20 { addr } => {
+            let info: TokenInfoResponse =
+                querier.query_wasm_smart(addr, &Cw20QueryMsg::TokenInfo {})?;
+            Ok(info.total_supply)
+        }
+        AssetInfo::Native { denom } => Ok(querier.query_supply(denom)?.amount),
+    }
+}
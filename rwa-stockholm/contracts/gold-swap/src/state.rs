@@ -0,0 +1,108 @@
+use cosmwasm_std::{Addr, Decimal, Empty, Timestamp, Uint128};
+use cw_storage_plus::{Item, Map};
+use rwa_stockholm_packages::msg::gold_swap::{
+    AssetInfo, Attestation, CircuitBreaker, DcaPlan, LimitOrder, PendingPairUpdate, RateSource,
+    ReserveBacking, SwapParams, Trade, VolumeLimits,
+};
+use rwa_stockholm_packages::ownership::Ownership;
+use rwa_stockholm_packages::pause::Pausable;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Pair {
+    pub base_token: AssetInfo,
+    pub quote_token: AssetInfo,
+    // Decimals of both tokens, read from the TokenInfo of CW20s when the pair is added
+    pub base_decimals: u8,
+    pub quote_decimals: u8,
+    pub rate: Decimal, // Whole quote tokens per whole base token, used in fixed mode
+    pub rate_updated_at: Option<Timestamp>, // Block time of the last rate change, if any
+    pub rate_delay_seconds: u64, // Minimum notice for a proposed rate
+    pub keeper_tip_bps: u16, // Share of each DCA swap paid to its keeper
+    pub rate_source: RateSource,
+    // Oracle or feeder rate of the last swap, the baseline for auto-pausing
+    pub reference_rate: Option<Decimal>,
+    pub sell: SwapParams,
+    pub buy: SwapParams,
+    pub swap_fee_bps: u16,
+    pub circuit_breaker: CircuitBreaker,
+    pub volume_limits: VolumeLimits,
+    pub enabled: bool, // Managed by the admin
+    pub paused: bool,  // Set by the pauser or the circuit breaker
+}
+
+// Base token volume swapped during one sub-bucket of a rolling window, starting at `start`
+// seconds
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct VolumeBucket {
+    pub start: u64,
+    pub volume: Uint128,
+}
+
+// A proposed fixed rate, announced ahead of `effective_at`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ScheduledRate {
+    pub rate: Decimal,
+    pub effective_at: Timestamp,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PriceSubmission {
+    pub price: Decimal,
+    pub submitted_at: Timestamp,
+}
+
+// Admin and the rate setter, pauser and treasury roles
+pub const OWNERSHIP: Ownership = Ownership::new("admin", "pending_admin", "roles");
+// Guardian and emergency pauses
+pub const PAUSE: Pausable = Pausable::new("guardian", "emergency_paused", "paused_operations");
+// Trading pairs keyed by base token denom or address
+pub const PAIRS: Map<&str, Pair> = Map::new("pairs");
+// Every asset traded by some pair, keyed by denom or address
+pub const ASSETS: Map<&str, AssetInfo> = Map::new("assets");
+// Proposed rates not yet moved into their pair, keyed by base token
+pub const SCHEDULED_RATES: Map<&str, ScheduledRate> = Map::new("scheduled_rates");
+// Rate source and rate delay changes waiting out their pair's delay, keyed by base token
+pub const PENDING_PAIR_UPDATES: Map<&str, PendingPairUpdate> = Map::new("pending_pair_updates");
+// Every rate each pair has had, keyed by (base token, seconds since the epoch it took effect)
+pub const RATE_HISTORY: Map<(&str, u64), Decimal> = Map::new("rate_history");
+// Block times of each pair's admin rate changes in the current circuit breaker window
+pub const RATE_UPDATES: Map<&str, Vec<Timestamp>> = Map::new("rate_updates");
+// Whitelisted price feeders
+pub const FEEDERS: Map<&Addr, Empty> = Map::new("feeders");
+// Latest submission of each feeder, keyed by (base token, feeder)
+pub const SUBMISSIONS: Map<(&str, &Addr), PriceSubmission> = Map::new("submissions");
+// Recent volume per (base token, swapper) and per pair, oldest sub-bucket first
+pub const ADDRESS_VOLUME: Map<(&str, &Addr), Vec<VolumeBucket>> =
+    Map::new("address_volume_buckets");
+pub const PAIR_VOLUME: Map<&str, Vec<VolumeBucket>> = Map::new("pair_volume_buckets");
+// Exempt from the volume limits
+pub const MARKET_MAKERS: Map<&Addr, Empty> = Map::new("market_makers");
+// Allowed to post reserve attestations
+pub const AUDITORS: Map<&Addr, Empty> = Map::new("auditors");
+// Reserve requirement and latest attestation of each backed pair, keyed by base token
+pub const RESERVE_BACKING: Map<&str, ReserveBacking> = Map::new("reserve_backing");
+pub const ATTESTATIONS: Map<&str, Attestation> = Map::new("attestations");
+// Swap receipts keyed by trade id, indexed by (user, trade id)
+pub const TRADE_COUNT: Item<u64> = Item::new("trade_count");
+pub const TRADES: Map<u64, Trade> = Map::new("trades");
+pub const USER_TRADES: Map<(&Addr, u64), Empty> = Map::new("user_trades");
+// Open limit orders keyed by order id, indexed by (base token, min rate atomics, order id) and
+// by (owner, order id). Escrowed tokens are not part of the reserves.
+pub const ORDER_COUNT: Item<u64> = Item::new("order_count");
+pub const ORDERS: Map<u64, LimitOrder> = Map::new("orders");
+pub const ORDER_BOOK: Map<(&str, u128, u64), Empty> = Map::new("order_book");
+// Book key of the last order the crank looked at on each pair
+pub const ORDER_CURSORS: Map<&str, (u128, u64)> = Map::new("order_cursors");
+pub const USER_ORDERS: Map<(&Addr, u64), Empty> = Map::new("user_orders");
+// DCA plans keyed by plan id, indexed by (next swap time in seconds, plan id) while active and
+// by (owner, plan id). Deposits are not part of the reserves.
+pub const DCA_PLAN_COUNT: Item<u64> = Item::new("dca_plan_count");
+pub const DCA_PLANS: Map<u64, DcaPlan> = Map::new("dca_plans");
+pub const DCA_SCHEDULE: Map<(u64, u64), Empty> = Map::new("dca_schedule");
+pub const USER_DCA_PLANS: Map<(&Addr, u64), Empty> = Map::new("user_dca_plans");
+// Tokens backing swaps, keyed by denom or address. Excludes collected fees.
+pub const RESERVES: Map<&str, Uint128> = Map::new("reserves");
+// Fees collected in each token since the last withdrawal, keyed by denom or address
+pub const COLLECTED_FEES: Map<&str, Uint128> = Map::new("collected_fees");
@@ -0,0 +1,141 @@
+use cosmwasm_std::{Decimal, Storage, Uint128};
+use rwa_stockholm_packages::msg::gold_swap::{AssetInfo, SwapParams};
+
+use crate::math::{
+    apply_swap_params, convert, convert_inverse, gross_for_return, Rounding, SwapOutcome,
+};
+use crate::state::{Pair, PAIRS};
+use crate::ContractError;
+
+/// The two ways through a pair. Rates are always quoted base-to-quote, so buying the base
+/// token converts at the inverse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Sell,
+    Buy,
+}
+
+impl Direction {
+    pub fn offer_token(self, pair: &Pair) -> &AssetInfo {
+        match self {
+            Direction::Sell => &pair.base_token,
+            Direction::Buy => &pair.quote_token,
+        }
+    }
+
+    pub fn ask_token(self, pair: &Pair) -> &AssetInfo {
+        match self {
+            Direction::Sell => &pair.quote_token,
+            Direction::Buy => &pair.base_token,
+        }
+    }
+
+    /// Label used in response attributes.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Direction::Sell => "sell",
+            Direction::Buy => "buy",
+        }
+    }
+
+    pub fn params(self, pair: &Pair) -> &SwapParams {
+        match self {
+            Direction::Sell => &pair.sell,
+            Direction::Buy => &pair.buy,
+        }
+    }
+}
+
+/// Finds the pair and direction of a swap from `offer` to `ask`, either of which may be left
+/// out as long as the other side identifies the pair: offering a base token sells it, asking
+/// for one buys it.
+pub fn route(
+    storage: &dyn Storage,
+    offer: Option<&str>,
+    ask: Option<&str>,
+) -> Result<(Pair, Direction), ContractError> {
+    if let Some(offer) = offer {
+        if let Some(pair) = PAIRS.may_load(storage, offer)? {
+            if ask.is_none_or(|ask| ask == pair.quote_token.as_str()) {
+                return Ok((pair, Direction::Sell));
+            }
+        }
+    }
+    if let Some(ask) = ask {
+        if let Some(pair) = PAIRS.may_load(storage, ask)? {
+            if offer.is_none_or(|offer| offer == pair.quote_token.as_str()) {
+                return Ok((pair, Direction::Buy));
+            }
+        }
+    }
+
+    Err(ContractError::PairNotFound {
+        token: offer.or(ask).unwrap_or_default().to_string(),
+    })
+}
+
+/// Computes what a swap of `offer_amount` pays out at `rate`. This is the only place swap
+/// output is calculated; `execute_receive` and the simulation queries both call it.
+pub fn simulate(
+    pair: &Pair,
+    direction: Direction,
+    rate: Decimal,
+    offer_amount: Uint128,
+) -> Result<SwapOutcome, ContractError> {
+    if rate.is_zero() {
+        return Err(ContractError::RateNotSet {});
+    }
+
+    let gross = match direction {
+        Direction::Sell => convert(
+            offer_amount,
+            rate,
+            pair.base_decimals,
+            pair.quote_decimals,
+            Rounding::Down,
+        )?,
+        Direction::Buy => convert_inverse(
+            offer_amount,
+            rate,
+            pair.quote_decimals,
+            pair.base_decimals,
+            Rounding::Down,
+        )?,
+    };
+
+    Ok(apply_swap_params(
+        gross,
+        direction.params(pair),
+        pair.swap_fee_bps,
+    ))
+}
+
+/// Smallest offer for which [`simulate`] pays out at least `ask_amount`.
+pub fn reverse_simulate(
+    pair: &Pair,
+    direction: Direction,
+    rate: Decimal,
+    ask_amount: Uint128,
+) -> Result<Uint128, ContractError> {
+    if rate.is_zero() {
+        return Err(ContractError::RateNotSet {});
+    }
+
+    let gross = gross_for_return(ask_amount, direction.params(pair), pair.swap_fee_bps)?;
+    match direction {
+        Direction::Sell => convert_inverse(
+            gross,
+            rate,
+            pair.quote_decimals,
+            pair.base_decimals,
+            Rounding::Up,
+        ),
+        Direction::Buy => convert(
+            gross,
+            rate,
+            pair.base_decimals,
+            pair.quote_decimals,
+            Rounding::Up,
+        ),
+    }
+}
@@ -0,0 +1,76 @@
+use cosmwasm_std::{Decimal, Env, StdResult, Storage, Timestamp};
+
+use crate::history::record_rate;
+use crate::state::{Pair, ScheduledRate, PAIRS, RATE_HISTORY, SCHEDULED_RATES};
+
+/// The pair's admin-set rate as of now and when it took effect: its scheduled rate once
+/// `effective_at` has passed, otherwise the stored one.
+pub fn fixed_rate(
+    storage: &dyn Storage,
+    env: &Env,
+    pair: &Pair,
+) -> StdResult<(Decimal, Option<Timestamp>)> {
+    Ok(match due_rate(storage, env, pair)? {
+        Some(scheduled) => (scheduled.rate, Some(scheduled.effective_at)),
+        None => (pair.rate, pair.rate_updated_at),
+    })
+}
+
+/// Moves a scheduled rate whose time has come into the pair, so the circuit breaker and the
+/// next proposal measure against it.
+pub fn activate_scheduled_rate(
+    storage: &mut dyn Storage,
+    env: &Env,
+    pair: &mut Pair,
+) -> StdResult<()> {
+    if let Some(scheduled) = due_rate(storage, env, pair)? {
+        pair.rate = scheduled.rate;
+        pair.rate_updated_at = Some(scheduled.effective_at);
+        PAIRS.save(storage, pair.base_token.as_str(), pair)?;
+        SCHEDULED_RATES.remove(storage, pair.base_token.as_str());
+    }
+    Ok(())
+}
+
+/// Announces `scheduled` for the pair, replacing any rate still pending, and enters it in the
+/// rate history from `effective_at` on.
+pub fn schedule_rate(
+    storage: &mut dyn Storage,
+    env: &Env,
+    pair: &mut Pair,
+    scheduled: &ScheduledRate,
+) -> StdResult<()> {
+    cancel_scheduled_rate(storage, env, pair)?;
+    SCHEDULED_RATES.save(storage, pair.base_token.as_str(), scheduled)?;
+    record_rate(
+        storage,
+        pair.base_token.as_str(),
+        scheduled.effective_at,
+        scheduled.rate,
+    )
+}
+
+/// Drops the pair's pending rate along with its history entry, and returns whether there was
+/// one. A scheduled rate that has already taken effect is activated instead.
+pub fn cancel_scheduled_rate(
+    storage: &mut dyn Storage,
+    env: &Env,
+    pair: &mut Pair,
+) -> StdResult<bool> {
+    activate_scheduled_rate(storage, env, pair)?;
+    let key = pair.base_token.as_str();
+    match SCHEDULED_RATES.may_load(storage, key)? {
+        Some(scheduled) => {
+            SCHEDULED_RATES.remove(storage, key);
+            RATE_HISTORY.remove(storage, (key, scheduled.effective_at.seconds()));
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+fn due_rate(storage: &dyn Storage, env: &Env, pair: &Pair) -> StdResult<Option<ScheduledRate>> {
+    Ok(SCHEDULED_RATES
+        .may_load(storage, pair.base_token.as_str())?
+        .filter(|scheduled| scheduled.effective_at <= env.block.time))
+}
@@ -0,0 +1,50 @@
+use cosmwasm_std::{Addr, Empty, Event, Order, StdResult, Storage};
+use cw_storage_plus::Bound;
+use rwa_stockholm_packages::msg::gold_swap::Trade;
+
+use crate::state::{TRADES, TRADE_COUNT, USER_TRADES};
+
+/// Stores the receipt of a completed swap under the next trade id and returns it with the id
+/// filled in.
+pub fn record_trade(storage: &mut dyn Storage, mut trade: Trade) -> StdResult<Trade> {
+    let id = TRADE_COUNT.may_load(storage)?.unwrap_or_default() + 1;
+    TRADE_COUNT.save(storage, &id)?;
+
+    trade.id = id;
+    TRADES.save(storage, id, &trade)?;
+    USER_TRADES.save(storage, (&trade.user, id), &Empty {})?;
+    Ok(trade)
+}
+
+/// Trades of `user` with an id above `start_after`, oldest first.
+pub fn trades_by_user(
+    storage: &dyn Storage,
+    user: &Addr,
+    start_after: Option<u64>,
+    limit: usize,
+) -> StdResult<Vec<Trade>> {
+    let start = start_after.map(Bound::exclusive);
+    USER_TRADES
+        .prefix(user)
+        .range(storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| TRADES.load(storage, item?.0))
+        .collect()
+}
+
+/// The `swap` event, emitted as `wasm-swap`. Indexers rely on its attributes, so existing ones
+/// must keep their name and format; add new ones rather than changing them.
+pub fn swap_event(trade: &Trade) -> Event {
+    Event::new("swap")
+        .add_attribute("trade_id", trade.id.to_string())
+        .add_attribute("user", trade.user.as_str())
+        .add_attribute("base_token", trade.base_token.as_str())
+        .add_attribute("offer_token", trade.offer.info.as_str())
+        .add_attribute("offer_amount", trade.offer.amount.to_string())
+        .add_attribute("ask_token", trade.ask.info.as_str())
+        .add_attribute("return_amount", trade.ask.amount.to_string())
+        .add_attribute("rate", trade.rate.to_string())
+        .add_attribute("fee_token", trade.fee.info.as_str())
+        .add_attribute("fee_amount", trade.fee.amount.to_string())
+        .add_attribute("timestamp", trade.timestamp.seconds().to_string())
+}
@@ -0,0 +1,279 @@
+use cosmwasm_std::{
+    entry_point, from_json, to_json_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo,
+    Response, StdResult, Storage, Uint128, WasmMsg,
+};
+use cw2::set_contract_version;
+use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+use rwa_stockholm_packages::msg::liquidity_pool::{
+    Asset, ConfigResponse, DepositResponse, ExecuteMsg, InstantiateMsg, MigrateMsg,
+    PoolInfoResponse, QueryMsg, ReceiveMsg, SudoMsg,
+};
+use rwa_stockholm_packages::pause::Operation;
+
+use crate::state::{Config, PoolInfo, CONFIG, DEPOSITS, PAUSE, POOL_INFO};
+use crate::ContractError;
+
+const CONTRACT_NAME: &str = "rwa-om-liquidity-pool";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let config = Config {
+        om_token_address: deps.api.addr_validate(&msg.om_token_address)?,
+    };
+    CONFIG.save(deps.storage, &config)?;
+    PAUSE.initialize(deps.storage, deps.api, &msg.guardian)?;
+
+    let pool_info = PoolInfo {
+        total_om_tokens: Uint128::zero(),
+        // Initialize fields for RWA
+    };
+    POOL_INFO.save(deps.storage, &pool_info)?;
+
+    Ok(Response::new().add_attribute("method", "instantiate"))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    if let Some(operation) = operation(&msg) {
+        PAUSE.assert_not_paused(deps.storage, operation)?;
+    }
+    match msg {
+        ExecuteMsg::DepositOmToken { amount } => deposit_om_token(deps, env, info, amount),
+        ExecuteMsg::DepositRwaToken {
+            token_id,
+            rwa_token_address,
+            valuation,
+        } => deposit_rwa_token(deps, info, token_id, rwa_token_address, valuation),
+        ExecuteMsg::Withdraw { asset } => withdraw_assets(deps, env, info, asset),
+        ExecuteMsg::Receive(msg) => receive_cw20(deps, env, info, msg),
+        ExecuteMsg::EmergencyPause { operation } => {
+            PAUSE.set_paused(deps.storage, &info.sender, operation, true)
+        }
+        ExecuteMsg::EmergencyUnpause { operation } => {
+            PAUSE.set_paused(deps.storage, &info.sender, operation, false)
+        }
+        ExecuteMsg::SetGuardian { guardian } => {
+            PAUSE.set_guardian(deps.storage, deps.api, &info.sender, &guardian)
+        }
+    }
+}
+
+// Emergency pause covering `msg`, if any. Users can always withdraw their deposits.
+fn operation(msg: &ExecuteMsg) -> Option<Operation> {
+    match msg {
+        ExecuteMsg::DepositOmToken { .. }
+        | ExecuteMsg::DepositRwaToken { .. }
+        | ExecuteMsg::Receive(_) => Some(Operation::Deposit),
+        _ => None,
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
+    let binary = match msg {
+        QueryMsg::Config {} => to_json_binary(&query_config(deps)?),
+        QueryMsg::PoolInfo {} => to_json_binary(&query_pool_info(deps)?),
+        QueryMsg::Deposit { address } => to_json_binary(&query_deposit(deps, address)?),
+        QueryMsg::PauseInfo {} => to_json_binary(&PAUSE.query(deps.storage)?),
+    };
+    Ok(binary?)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::new().add_attribute("method", "migrate"))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn sudo(deps: DepsMut, _env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
+    match msg {
+        SudoMsg::UpdateConfig { om_token_address } => {
+            let config = Config {
+                om_token_address: deps.api.addr_validate(&om_token_address)?,
+            };
+            CONFIG.save(deps.storage, &config)?;
+
+            Ok(Response::new()
+                .add_attribute("action", "sudo_update_config")
+                .add_attribute("om_token_address", om_token_address))
+        }
+    }
+}
+
+fn deposit_om_token(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    credit_om_tokens(deps.storage, &info.sender, amount)?;
+
+    // Fails the whole deposit unless the tokens actually arrive
+    let transfer_from_msg = Cw20ExecuteMsg::TransferFrom {
+        owner: info.sender.to_string(),
+        recipient: env.contract.address.to_string(),
+        amount,
+    };
+    let wasm_msg = WasmMsg::Execute {
+        contract_addr: config.om_token_address.to_string(),
+        msg: to_json_binary(&transfer_from_msg)?,
+        funds: vec![],
+    };
+
+    Ok(Response::new()
+        .add_message(wasm_msg)
+        .add_attribute("action", "deposit_om_token")
+        .add_attribute("from", info.sender)
+        .add_attribute("amount", amount.to_string()))
+}
+
+fn credit_om_tokens(
+    storage: &mut dyn Storage,
+    depositor: &Addr,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    let mut pool_info = POOL_INFO.load(storage)?;
+    pool_info.total_om_tokens = pool_info.total_om_tokens.checked_add(amount)?;
+    POOL_INFO.save(storage, &pool_info)?;
+
+    let deposit = DEPOSITS.may_load(storage, depositor)?.unwrap_or_default();
+    DEPOSITS.save(storage, depositor, &deposit.checked_add(amount)?)?;
+    Ok(())
+}
+
+fn deposit_rwa_token(
+    _deps: DepsMut,
+    info: MessageInfo,
+    token_id: String,
+    rwa_token_address: String,
+    _valuation: Uint128,
+) -> Result<Response, ContractError> {
+    // RWA token deposit logic here
+    // You would need a way to verify ownership and lock the token or represent the deposit in some way
+
+    Ok(Response::new()
+        .add_attribute("action", "deposit_rwa_token")
+        .add_attribute("from", info.sender)
+        .add_attribute("token_id", token_id)
+        .add_attribute("rwa_token_address", rwa_token_address))
+}
+
+fn withdraw_assets(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    asset: Asset,
+) -> Result<Response, ContractError> {
+    match asset {
+        Asset::OmToken(amount) => {
+            // Only the sender's own deposits can be withdrawn
+            let deposit = DEPOSITS
+                .may_load(deps.storage, &info.sender)?
+                .unwrap_or_default();
+            if amount > deposit {
+                return Err(ContractError::InsufficientPoolBalance {
+                    requested: amount,
+                    available: deposit,
+                });
+            }
+            DEPOSITS.save(deps.storage, &info.sender, &(deposit - amount))?;
+            let mut pool_info = POOL_INFO.load(deps.storage)?;
+            pool_info.total_om_tokens -= amount;
+            POOL_INFO.save(deps.storage, &pool_info)?;
+            let config = CONFIG.load(deps.storage)?;
+
+            // Transfer OM tokens back to the requester
+            let om_transfer_msg = Cw20ExecuteMsg::Transfer {
+                recipient: info.sender.to_string(),
+                amount,
+            };
+
+            let wasm_msg = WasmMsg::Execute {
+                contract_addr: config.om_token_address.to_string(),
+                msg: to_json_binary(&om_transfer_msg)?,
+                funds: vec![],
+            };
+
+            Ok(Response::new()
+                .add_message(wasm_msg)
+                .add_attribute("action", "withdraw_om_token")
+                .add_attribute("amount", amount.to_string()))
+        }
+        Asset::RwaToken {
+            token_id,
+            rwa_token_address,
+        } => {
+            // Logic for withdrawing RWA tokens
+            // This would involve transferring the RWA token back to the owner and possibly updating internal state to reflect the withdrawal
+
+            Ok(Response::new()
+                .add_attribute("action", "withdraw_rwa_token")
+                .add_attribute("token_id", token_id)
+                .add_attribute("rwa_token_address", rwa_token_address))
+        }
+    }
+}
+
+fn receive_cw20(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    cw20_msg: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    // Only the OM token is credited; `info.sender` is the token contract
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.om_token_address {
+        return Err(ContractError::InvalidToken {
+            token: info.sender.to_string(),
+        });
+    }
+    let depositor = deps.api.addr_validate(&cw20_msg.sender)?;
+    match from_json(&cw20_msg.msg)? {
+        ReceiveMsg::DepositOmToken {} => {
+            credit_om_tokens(deps.storage, &depositor, cw20_msg.amount)?
+        }
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "deposit_om_token")
+        .add_attribute("from", depositor)
+        .add_attribute("amount", cw20_msg.amount.to_string()))
+}
+
+fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    Ok(ConfigResponse {
+        om_token_address: config.om_token_address.to_string(),
+    })
+}
+
+fn query_pool_info(deps: Deps) -> StdResult<PoolInfoResponse> {
+    let pool_info = POOL_INFO.load(deps.storage)?;
+    Ok(PoolInfoResponse {
+        total_om_tokens: pool_info.total_om_tokens,
+    })
+}
+
+fn query_deposit(deps: Deps, address: String) -> StdResult<DepositResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    Ok(DepositResponse {
+        om_tokens: DEPOSITS
+            .may_load(deps.storage, &address)?
+            .unwrap_or_default(),
+    })
+}
@@ -0,0 +1,23 @@
+use cosmwasm_std::{Addr, Uint128};
+use cw_storage_plus::{Item, Map};
+use rwa_stockholm_packages::pause::Pausable;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Config {
+    pub om_token_address: Addr,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PoolInfo {
+    pub total_om_tokens: Uint128,
+    // Additional fields for RWA tracking if needed
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const POOL_INFO: Item<PoolInfo> = Item::new("pool_info");
+// OM each depositor can withdraw
+pub const DEPOSITS: Map<&Addr, Uint128> = Map::new("om_deposits");
+// Guardian and emergency pauses
+pub const PAUSE: Pausable = Pausable::new("guardian", "emergency_paused", "paused_operations");
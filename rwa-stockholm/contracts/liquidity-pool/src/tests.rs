@@ -0,0 +1,134 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier};
+use cosmwasm_std::{
+    from_json, to_json_binary, CosmosMsg, MemoryStorage, OwnedDeps, Uint128, WasmMsg,
+};
+use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+use rwa_stockholm_packages::msg::liquidity_pool::{
+    Asset, DepositResponse, ExecuteMsg, InstantiateMsg, QueryMsg, ReceiveMsg,
+};
+use rwa_stockholm_packages::pause::Operation;
+
+use crate::contract::{execute, instantiate, query};
+use crate::ContractError;
+
+const OM_TOKEN: &str = "om_token";
+const GUARDIAN: &str = "guardian";
+const ALICE: &str = "alice";
+const BOB: &str = "bob";
+
+type Deps = OwnedDeps<MemoryStorage, MockApi, MockQuerier>;
+
+fn setup() -> Deps {
+    let mut deps = mock_dependencies();
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("creator", &[]),
+        InstantiateMsg {
+            om_token_address: OM_TOKEN.to_string(),
+            guardian: GUARDIAN.to_string(),
+        },
+    )
+    .unwrap();
+    deps
+}
+
+fn send_om(deps: &mut Deps, token: &str, from: &str, amount: u128) -> Result<(), ContractError> {
+    let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+        sender: from.to_string(),
+        amount: Uint128::new(amount),
+        msg: to_json_binary(&ReceiveMsg::DepositOmToken {}).unwrap(),
+    });
+    execute(deps.as_mut(), mock_env(), mock_info(token, &[]), msg).map(|_| ())
+}
+
+fn withdraw(deps: &mut Deps, sender: &str, amount: u128) -> Result<(), ContractError> {
+    let msg = ExecuteMsg::Withdraw {
+        asset: Asset::OmToken(Uint128::new(amount)),
+    };
+    execute(deps.as_mut(), mock_env(), mock_info(sender, &[]), msg).map(|_| ())
+}
+
+fn deposit_of(deps: &Deps, address: &str) -> Uint128 {
+    let msg = QueryMsg::Deposit {
+        address: address.to_string(),
+    };
+    let response: DepositResponse =
+        from_json(query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+    response.om_tokens
+}
+
+#[test]
+fn only_om_sent_to_the_pool_is_credited() {
+    let mut deps = setup();
+
+    let err = send_om(&mut deps, "other_token", ALICE, 100).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidToken {
+            token: "other_token".to_string()
+        }
+    );
+    send_om(&mut deps, OM_TOKEN, ALICE, 100).unwrap();
+    assert_eq!(deposit_of(&deps, ALICE), Uint128::new(100));
+}
+
+#[test]
+fn deposit_pulls_the_tokens_it_credits() {
+    let mut deps = setup();
+
+    let msg = ExecuteMsg::DepositOmToken {
+        amount: Uint128::new(100),
+    };
+    let response = execute(deps.as_mut(), mock_env(), mock_info(ALICE, &[]), msg).unwrap();
+    assert_eq!(
+        response.messages[0].msg,
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: OM_TOKEN.to_string(),
+            msg: to_json_binary(&Cw20ExecuteMsg::TransferFrom {
+                owner: ALICE.to_string(),
+                recipient: mock_env().contract.address.to_string(),
+                amount: Uint128::new(100),
+            })
+            .unwrap(),
+            funds: vec![],
+        })
+    );
+    assert_eq!(deposit_of(&deps, ALICE), Uint128::new(100));
+}
+
+#[test]
+fn users_only_withdraw_their_own_deposits() {
+    let mut deps = setup();
+    send_om(&mut deps, OM_TOKEN, ALICE, 100).unwrap();
+
+    let err = withdraw(&mut deps, BOB, 1).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InsufficientPoolBalance {
+            requested: Uint128::new(1),
+            available: Uint128::zero(),
+        }
+    );
+    withdraw(&mut deps, ALICE, 60).unwrap();
+    assert_eq!(deposit_of(&deps, ALICE), Uint128::new(40));
+}
+
+#[test]
+fn withdrawals_stay_open_during_a_pause() {
+    let mut deps = setup();
+    send_om(&mut deps, OM_TOKEN, ALICE, 100).unwrap();
+
+    for operation in [None, Some(Operation::Withdraw)] {
+        let msg = ExecuteMsg::EmergencyPause { operation };
+        execute(deps.as_mut(), mock_env(), mock_info(GUARDIAN, &[]), msg).unwrap();
+    }
+    let err = send_om(&mut deps, OM_TOKEN, ALICE, 100).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::OperationPaused {
+            operation: "deposit".to_string()
+        }
+    );
+    withdraw(&mut deps, ALICE, 100).unwrap();
+}
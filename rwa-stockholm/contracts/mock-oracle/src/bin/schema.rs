@@ -1,11 +1,12 @@
 use cosmwasm_schema::write_api;
 
-use rwa_stockholm::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use rwa_stockholm_packages::msg::oracle::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
 
 fn main() {
     write_api! {
         instantiate: InstantiateMsg,
         execute: ExecuteMsg,
         query: QueryMsg,
+        migrate: MigrateMsg,
     }
 }
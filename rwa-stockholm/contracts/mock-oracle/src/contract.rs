@@ -0,0 +1,104 @@
+/*
+A minimal price feed for exercising gold_swap's oracle mode in tests and on testnets. The admin
+sets a price, confidence band and publish time per pair; anyone can query them back. It performs
+no aggregation or validation and must not be used as a production price source.
+*/
+
+use cosmwasm_std::{
+    entry_point, to_json_binary, Binary, Decimal, Deps, DepsMut, Env, MessageInfo, Response,
+    StdResult, Timestamp,
+};
+use cw2::set_contract_version;
+use rwa_stockholm_packages::msg::oracle::{
+    ExecuteMsg, InstantiateMsg, MigrateMsg, PriceResponse, QueryMsg,
+};
+
+use crate::state::{Config, PriceFeed, CONFIG, PRICES};
+use crate::ContractError;
+
+const CONTRACT_NAME: &str = "mock-oracle";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let config = Config {
+        admin: deps.api.addr_validate(&msg.admin)?,
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attribute("method", "instantiate"))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::SetPrice {
+            pair,
+            price,
+            confidence,
+            publish_time,
+        } => set_price(deps, env, info, pair, price, confidence, publish_time),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
+    let binary = match msg {
+        QueryMsg::Price { pair } => to_json_binary(&query_price(deps, pair)?),
+    };
+    Ok(binary?)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::new().add_attribute("method", "migrate"))
+}
+
+fn set_price(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    pair: String,
+    price: Decimal,
+    confidence: Decimal,
+    publish_time: Option<Timestamp>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let feed = PriceFeed {
+        price,
+        confidence,
+        publish_time: publish_time.unwrap_or(env.block.time),
+    };
+    PRICES.save(deps.storage, &pair, &feed)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_price")
+        .add_attribute("pair", pair)
+        .add_attribute("price", price.to_string()))
+}
+
+fn query_price(deps: Deps, pair: String) -> StdResult<PriceResponse> {
+    let feed = PRICES.load(deps.storage, &pair)?;
+    Ok(PriceResponse {
+        price: feed.price,
+        confidence: feed.confidence,
+        publish_time: feed.publish_time,
+    })
+}
@@ -0,0 +1,4 @@
+pub mod contract;
+pub mod state;
+
+pub use rwa_stockholm_packages::ContractError;
@@ -0,0 +1,19 @@
+use cosmwasm_std::{Addr, Decimal, Timestamp};
+use cw_storage_plus::{Item, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Config {
+    pub admin: Addr,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PriceFeed {
+    pub price: Decimal,
+    pub confidence: Decimal,
+    pub publish_time: Timestamp,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const PRICES: Map<&str, PriceFeed> = Map::new("prices"); // Keyed by pair, e.g. "XAU/OM"
@@ -0,0 +1,598 @@
+/*
+Key Components and Considerations:
+
+1. Ownership Verification: Before listing an NFT for sale, the contract verifies that the caller (info.sender) is the current owner of the NFT. This ensures that only the rightful owner can initiate a sale.
+2. Sale Information: When an NFT is listed for sale, the contract records the sale information, including the token ID, seller's address, and the sale price. This information is crucial for facilitating the purchase transaction later on.
+3. Purchase Transaction: In the purchase function (try_buy_nft), the contract checks if the token ID matches an active listing and if the buyer has paid exactly the listed price (info.funds), so no excess is left behind in the contract. Upon successful validation, the contract removes the sale listing, transfers the NFT to the buyer, and the sale funds to the seller.
+4. Error Handling: Every entry point returns the shared ContractError, e.g. ContractError::Unauthorized for unauthorized actions, ContractError::ListingNotFound for unknown listings and ContractError::IncorrectPayment for any payment other than the exact price. Errors raised by the cw721 base are wrapped in ContractError::Cw721.
+5. Leasing: In the style of ERC-4907, an owner (or anyone the owner approved through cw721 approvals) can grant a separate "user" role until an expiry, either directly (SetUser) or by publishing lease terms that renters pay for per period (OfferLease / RentNft). The user role never touches the cw721 owner field, so legal ownership stays with the owner, and it lapses on its own once the expiry passes. A user role must always expire, and a role granted with SetUser can be taken back early with RevokeUser; a paid rental cannot.
+6. Storage Management: Active sale listings (SALES), lease offers (LEASE_OFFERS) and lease users (USERS) are kept in maps keyed by token ID, so any number of tokens can be listed or leased at the same time.
+*/
+
+use cosmwasm_std::{
+    attr, entry_point, to_json_binary, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Empty, Env,
+    MessageInfo, Response, StdError, StdResult, Storage, Uint128,
+};
+use cw2::set_contract_version;
+use cw721::Expiration;
+use cw721_base::{
+    msg::{ExecuteMsg as Cw721ExecuteMsg, QueryMsg as Cw721QueryMsg},
+    Cw721Contract, Extension,
+};
+use rwa_stockholm_packages::msg::rwa_nft::{
+    ExecuteMsg, InstantiateMsg, LeaseOfferResponse, ListingResponse, MigrateMsg, QueryMsg, SudoMsg,
+    UserOfResponse,
+};
+use rwa_stockholm_packages::pause::Operation;
+
+use crate::state::{LeaseOffer, SaleInfo, UserInfo, LEASE_OFFERS, PAUSE, SALES, USERS};
+use crate::ContractError;
+
+const CONTRACT_NAME: &str = "crates.io:rwa-nft";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub type Cw721BaseContract<'a> = Cw721Contract<'a, Extension, Empty, Empty, Empty>;
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    let res =
+        Cw721BaseContract::default().instantiate(deps.branch(), env, info, msg.cw721_base_msg)?;
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    PAUSE.initialize(deps.storage, deps.api, &msg.guardian)?;
+    Ok(res)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    if let Some(operation) = operation(&msg) {
+        PAUSE.assert_not_paused(deps.storage, operation)?;
+    }
+    match msg {
+        ExecuteMsg::Cw721Base(base_msg) => {
+            match &base_msg {
+                Cw721ExecuteMsg::TransferNft { token_id, .. }
+                | Cw721ExecuteMsg::SendNft { token_id, .. }
+                | Cw721ExecuteMsg::Burn { token_id } => {
+                    ensure_not_leased(deps.storage, &env, token_id)?;
+                    LEASE_OFFERS.remove(deps.storage, token_id);
+                    SALES.remove(deps.storage, token_id);
+                }
+                _ => {}
+            }
+            Ok(Cw721BaseContract::default().execute(deps, env, info, base_msg)?)
+        }
+        ExecuteMsg::ListNftForSale { token_id, price } => {
+            try_list_for_sale(deps, info, token_id, price)
+        }
+        ExecuteMsg::BuyNft { token_id } => try_buy_nft(deps, env, info, token_id),
+        ExecuteMsg::SetUser {
+            token_id,
+            user,
+            expires,
+        } => try_set_user(deps, env, info, token_id, user, expires),
+        ExecuteMsg::RevokeUser { token_id } => try_revoke_user(deps, env, info, token_id),
+        ExecuteMsg::OfferLease {
+            token_id,
+            rent_per_period,
+            period_seconds,
+            max_periods,
+        } => try_offer_lease(
+            deps,
+            env,
+            info,
+            token_id,
+            rent_per_period,
+            period_seconds,
+            max_periods,
+        ),
+        ExecuteMsg::CancelLeaseOffer { token_id } => {
+            try_cancel_lease_offer(deps, env, info, token_id)
+        }
+        ExecuteMsg::RentNft { token_id, periods } => {
+            try_rent_nft(deps, env, info, token_id, periods)
+        }
+        ExecuteMsg::EmergencyPause { operation } => {
+            PAUSE.set_paused(deps.storage, &info.sender, operation, true)
+        }
+        ExecuteMsg::EmergencyUnpause { operation } => {
+            PAUSE.set_paused(deps.storage, &info.sender, operation, false)
+        }
+        ExecuteMsg::SetGuardian { guardian } => {
+            PAUSE.set_guardian(deps.storage, deps.api, &info.sender, &guardian)
+        }
+    }
+}
+
+// Emergency pause covering `msg`, if any. Plain cw721 transfers and cancellations are never
+// paused so owners keep control of their tokens.
+fn operation(msg: &ExecuteMsg) -> Option<Operation> {
+    match msg {
+        ExecuteMsg::ListNftForSale { .. } | ExecuteMsg::OfferLease { .. } => Some(Operation::List),
+        ExecuteMsg::BuyNft { .. } | ExecuteMsg::RentNft { .. } => Some(Operation::Buy),
+        _ => None,
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
+    let base = Cw721BaseContract::default();
+    let binary = match msg {
+        QueryMsg::OwnerOf {
+            token_id,
+            include_expired,
+        } => base.query(
+            deps,
+            env,
+            Cw721QueryMsg::OwnerOf {
+                token_id,
+                include_expired,
+            },
+        ),
+        QueryMsg::Approval {
+            token_id,
+            spender,
+            include_expired,
+        } => base.query(
+            deps,
+            env,
+            Cw721QueryMsg::Approval {
+                token_id,
+                spender,
+                include_expired,
+            },
+        ),
+        QueryMsg::Approvals {
+            token_id,
+            include_expired,
+        } => base.query(
+            deps,
+            env,
+            Cw721QueryMsg::Approvals {
+                token_id,
+                include_expired,
+            },
+        ),
+        QueryMsg::AllOperators {
+            owner,
+            include_expired,
+            start_after,
+            limit,
+        } => base.query(
+            deps,
+            env,
+            Cw721QueryMsg::AllOperators {
+                owner,
+                include_expired,
+                start_after,
+                limit,
+            },
+        ),
+        QueryMsg::NftInfo { token_id } => {
+            base.query(deps, env, Cw721QueryMsg::NftInfo { token_id })
+        }
+        QueryMsg::AllNftInfo {
+            token_id,
+            include_expired,
+        } => base.query(
+            deps,
+            env,
+            Cw721QueryMsg::AllNftInfo {
+                token_id,
+                include_expired,
+            },
+        ),
+        QueryMsg::Tokens {
+            owner,
+            start_after,
+            limit,
+        } => base.query(
+            deps,
+            env,
+            Cw721QueryMsg::Tokens {
+                owner,
+                start_after,
+                limit,
+            },
+        ),
+        QueryMsg::AllTokens { start_after, limit } => {
+            base.query(deps, env, Cw721QueryMsg::AllTokens { start_after, limit })
+        }
+        QueryMsg::NumTokens {} => base.query(deps, env, Cw721QueryMsg::NumTokens {}),
+        QueryMsg::ContractInfo {} => base.query(deps, env, Cw721QueryMsg::ContractInfo {}),
+        QueryMsg::Minter {} => base.query(deps, env, Cw721QueryMsg::Minter {}),
+        QueryMsg::Listing { token_id } => to_json_binary(&query_listing(deps, token_id)?),
+        QueryMsg::UserOf { token_id } => to_json_binary(&query_user_of(deps, env, token_id)?),
+        QueryMsg::LeaseOffer { token_id } => to_json_binary(&query_lease_offer(deps, token_id)?),
+        QueryMsg::PauseInfo {} => to_json_binary(&PAUSE.query(deps.storage)?),
+    };
+    Ok(binary?)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::new().add_attribute("method", "migrate"))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn sudo(deps: DepsMut, _env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
+    match msg {
+        SudoMsg::RemoveListing { token_id } => {
+            if !SALES.has(deps.storage, &token_id) {
+                return Err(ContractError::ListingNotFound { token_id });
+            }
+            SALES.remove(deps.storage, &token_id);
+
+            Ok(Response::new()
+                .add_attribute("action", "sudo_remove_listing")
+                .add_attribute("token_id", token_id))
+        }
+    }
+}
+
+fn try_list_for_sale(
+    deps: DepsMut,
+    info: MessageInfo,
+    token_id: String,
+    price: Coin,
+) -> Result<Response, ContractError> {
+    let token = Cw721BaseContract::default()
+        .tokens
+        .load(deps.storage, &token_id)?;
+
+    if info.sender != token.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let sale_info = SaleInfo {
+        token_id: token_id.clone(),
+        seller: info.sender.to_string(),
+        price,
+    };
+
+    SALES.save(deps.storage, &token_id, &sale_info)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "list_for_sale"),
+        attr("token_id", token_id),
+        attr("seller", info.sender),
+        attr("price", sale_info.price.to_string()),
+    ]))
+}
+
+fn try_buy_nft(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+) -> Result<Response, ContractError> {
+    let sale_info =
+        SALES
+            .may_load(deps.storage, &token_id)?
+            .ok_or_else(|| ContractError::ListingNotFound {
+                token_id: token_id.clone(),
+            })?;
+    ensure_not_leased(deps.storage, &env, &token_id)?;
+
+    // The listing is only good while the seller still owns the token
+    let token = Cw721BaseContract::default()
+        .tokens
+        .load(deps.storage, &token_id)?;
+    if token.owner != sale_info.seller {
+        SALES.remove(deps.storage, &token_id);
+        return Err(ContractError::ListingNotFound { token_id });
+    }
+
+    assert_exact_payment(&info, &sale_info.price)?;
+
+    SALES.remove(deps.storage, &token_id);
+    LEASE_OFFERS.remove(deps.storage, &token_id);
+
+    // Transfer the NFT to the buyer
+    Cw721BaseContract::default()
+        .tokens
+        .update(deps.storage, &token_id, |token| match token {
+            Some(mut token) => {
+                token.owner = info.sender.clone();
+                token.approvals = vec![];
+                Ok(token)
+            }
+            None => Err(StdError::not_found("cw721_base::state::TokenInfo")),
+        })?;
+
+    // Transfer funds to the seller
+    let seller = deps.api.addr_validate(&sale_info.seller)?;
+    let send_msg = CosmosMsg::Bank(BankMsg::Send {
+        to_address: seller.to_string(),
+        amount: vec![sale_info.price.clone()],
+    });
+
+    Ok(Response::new().add_message(send_msg).add_attributes(vec![
+        attr("action", "buy_nft"),
+        attr("token_id", token_id),
+        attr("buyer", info.sender),
+        attr("price", sale_info.price.to_string()),
+    ]))
+}
+
+fn try_set_user(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+    user: String,
+    expires: Expiration,
+) -> Result<Response, ContractError> {
+    check_can_lease(deps.as_ref(), &env, &info, &token_id)?;
+    ensure_not_leased(deps.storage, &env, &token_id)?;
+    if expires.is_expired(&env.block) {
+        return Err(ContractError::Expired {});
+    }
+    // A role that never expires could never be ended, so the token could never be sold again
+    if matches!(expires, Expiration::Never {}) {
+        return Err(ContractError::InvalidLeaseTerms {
+            reason: "the user role must expire".to_string(),
+        });
+    }
+
+    let user = deps.api.addr_validate(&user)?;
+    USERS.save(
+        deps.storage,
+        &token_id,
+        &UserInfo {
+            user: user.to_string(),
+            expires,
+            rented: false,
+        },
+    )?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "set_user"),
+        attr("token_id", token_id),
+        attr("user", user),
+        attr("expires", expires.to_string()),
+    ]))
+}
+
+fn try_revoke_user(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+) -> Result<Response, ContractError> {
+    check_can_lease(deps.as_ref(), &env, &info, &token_id)?;
+    // A renter paid for the whole lease, so only roles granted for free can be taken back
+    match active_user(deps.storage, &env, &token_id)? {
+        Some(user_info) if user_info.rented => {
+            return Err(ContractError::LeaseActive {
+                token_id,
+                user: user_info.user,
+                expires: user_info.expires.to_string(),
+            })
+        }
+        Some(_) => {}
+        None => return Err(ContractError::UserNotFound { token_id }),
+    }
+    USERS.remove(deps.storage, &token_id);
+
+    Ok(Response::new()
+        .add_attribute("action", "revoke_user")
+        .add_attribute("token_id", token_id))
+}
+
+fn try_offer_lease(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+    rent_per_period: Coin,
+    period_seconds: u64,
+    max_periods: u64,
+) -> Result<Response, ContractError> {
+    let owner = check_can_lease(deps.as_ref(), &env, &info, &token_id)?;
+    if rent_per_period.amount.is_zero() {
+        return Err(ContractError::InvalidLeaseTerms {
+            reason: "rent must be positive".to_string(),
+        });
+    }
+    if period_seconds == 0 || max_periods == 0 {
+        return Err(ContractError::InvalidLeaseTerms {
+            reason: "period and max periods must be positive".to_string(),
+        });
+    }
+
+    let offer = LeaseOffer {
+        token_id: token_id.clone(),
+        lessor: owner,
+        rent_per_period,
+        period_seconds,
+        max_periods,
+    };
+    LEASE_OFFERS.save(deps.storage, &token_id, &offer)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "offer_lease"),
+        attr("token_id", token_id),
+        attr("lessor", offer.lessor),
+        attr("rent_per_period", offer.rent_per_period.to_string()),
+        attr("period_seconds", period_seconds.to_string()),
+        attr("max_periods", max_periods.to_string()),
+    ]))
+}
+
+fn try_cancel_lease_offer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+) -> Result<Response, ContractError> {
+    check_can_lease(deps.as_ref(), &env, &info, &token_id)?;
+    LEASE_OFFERS.remove(deps.storage, &token_id);
+
+    Ok(Response::new()
+        .add_attribute("action", "cancel_lease_offer")
+        .add_attribute("token_id", token_id))
+}
+
+fn try_rent_nft(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+    periods: u64,
+) -> Result<Response, ContractError> {
+    let offer = LEASE_OFFERS
+        .may_load(deps.storage, &token_id)?
+        .ok_or_else(|| ContractError::LeaseOfferNotFound {
+            token_id: token_id.clone(),
+        })?;
+    ensure_not_leased(deps.storage, &env, &token_id)?;
+
+    // The offer is only good while the lessor still owns the token
+    let token = Cw721BaseContract::default()
+        .tokens
+        .load(deps.storage, &token_id)?;
+    if token.owner != offer.lessor {
+        LEASE_OFFERS.remove(deps.storage, &token_id);
+        return Err(ContractError::LeaseOfferNotFound { token_id });
+    }
+    if periods == 0 || periods > offer.max_periods {
+        return Err(ContractError::InvalidLeaseTerms {
+            reason: format!("lease must be between 1 and {} periods", offer.max_periods),
+        });
+    }
+
+    let rent = Coin {
+        denom: offer.rent_per_period.denom.clone(),
+        amount: offer
+            .rent_per_period
+            .amount
+            .checked_mul(Uint128::from(periods))?,
+    };
+    assert_exact_payment(&info, &rent)?;
+
+    let duration = offer.period_seconds.checked_mul(periods).ok_or_else(|| {
+        ContractError::InvalidLeaseTerms {
+            reason: "lease duration overflows".to_string(),
+        }
+    })?;
+    let expires = Expiration::AtTime(env.block.time.plus_seconds(duration));
+    USERS.save(
+        deps.storage,
+        &token_id,
+        &UserInfo {
+            user: info.sender.to_string(),
+            expires,
+            rented: true,
+        },
+    )?;
+
+    // Rent goes straight to the owner; the token itself never moves
+    let send_msg = CosmosMsg::Bank(BankMsg::Send {
+        to_address: offer.lessor.clone(),
+        amount: vec![rent.clone()],
+    });
+
+    Ok(Response::new().add_message(send_msg).add_attributes(vec![
+        attr("action", "rent_nft"),
+        attr("token_id", token_id),
+        attr("user", info.sender),
+        attr("lessor", offer.lessor),
+        attr("rent", rent.to_string()),
+        attr("expires", expires.to_string()),
+    ]))
+}
+
+fn query_listing(deps: Deps, token_id: String) -> Result<ListingResponse, ContractError> {
+    let sale_info = SALES
+        .may_load(deps.storage, &token_id)?
+        .ok_or(ContractError::ListingNotFound { token_id })?;
+    Ok(ListingResponse {
+        token_id: sale_info.token_id,
+        seller: sale_info.seller,
+        price: sale_info.price,
+    })
+}
+
+fn query_lease_offer(deps: Deps, token_id: String) -> Result<LeaseOfferResponse, ContractError> {
+    let offer = LEASE_OFFERS
+        .may_load(deps.storage, &token_id)?
+        .ok_or(ContractError::LeaseOfferNotFound { token_id })?;
+    Ok(LeaseOfferResponse {
+        token_id: offer.token_id,
+        lessor: offer.lessor,
+        rent_per_period: offer.rent_per_period,
+        period_seconds: offer.period_seconds,
+        max_periods: offer.max_periods,
+    })
+}
+
+fn query_user_of(deps: Deps, env: Env, token_id: String) -> StdResult<UserOfResponse> {
+    match active_user(deps.storage, &env, &token_id)? {
+        Some(info) => Ok(UserOfResponse {
+            user: Some(info.user),
+            expires: Some(info.expires),
+        }),
+        None => Ok(UserOfResponse {
+            user: None,
+            expires: None,
+        }),
+    }
+}
+
+// Returns the current user of a token, treating an expired lease as no lease at all
+fn active_user(storage: &dyn Storage, env: &Env, token_id: &str) -> StdResult<Option<UserInfo>> {
+    Ok(USERS
+        .may_load(storage, token_id)?
+        .filter(|info| !info.expires.is_expired(&env.block)))
+}
+
+fn ensure_not_leased(
+    storage: &dyn Storage,
+    env: &Env,
+    token_id: &str,
+) -> Result<(), ContractError> {
+    match active_user(storage, env, token_id)? {
+        Some(info) => Err(ContractError::LeaseActive {
+            token_id: token_id.to_string(),
+            user: info.user,
+            expires: info.expires.to_string(),
+        }),
+        None => Ok(()),
+    }
+}
+
+// Sales and rentals take exactly the price in a single coin, so no excess is stranded in the
+// contract
+fn assert_exact_payment(info: &MessageInfo, expected: &Coin) -> Result<(), ContractError> {
+    match info.funds.as_slice() {
+        [coin] if coin == expected => Ok(()),
+        _ => Err(ContractError::IncorrectPayment {
+            expected: expected.to_string(),
+        }),
+    }
+}
+
+// Leasing follows the cw721 transfer rules: the owner, an approved spender or an operator
+// may lease the token out. Returns the owner, who receives the rent.
+fn check_can_lease(
+    deps: Deps,
+    env: &Env,
+    info: &MessageInfo,
+    token_id: &str,
+) -> Result<String, ContractError> {
+    let contract = Cw721BaseContract::default();
+    let token = contract.tokens.load(deps.storage, token_id)?;
+    contract.check_can_send(deps, env, info, &token)?;
+    Ok(token.owner.to_string())
+}
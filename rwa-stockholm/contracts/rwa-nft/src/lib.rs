@@ -0,0 +1,6 @@
+pub mod contract;
+pub mod state;
+#[cfg(test)]
+mod tests;
+
+pub use rwa_stockholm_packages::ContractError;
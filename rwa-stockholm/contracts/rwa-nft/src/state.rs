@@ -0,0 +1,37 @@
+use cosmwasm_std::Coin;
+use cw721::Expiration;
+use cw_storage_plus::Map;
+use rwa_stockholm_packages::pause::Pausable;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SaleInfo {
+    pub token_id: String,
+    pub seller: String,
+    pub price: Coin,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LeaseOffer {
+    pub token_id: String,
+    pub lessor: String,
+    pub rent_per_period: Coin,
+    pub period_seconds: u64,
+    pub max_periods: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct UserInfo {
+    pub user: String,
+    pub expires: Expiration,
+    #[serde(default)]
+    pub rented: bool, // Paid through RentNft, so the owner cannot revoke it early
+}
+
+// Storage for sales and leases, keyed by token_id
+pub const SALES: Map<&str, SaleInfo> = Map::new("sales");
+pub const LEASE_OFFERS: Map<&str, LeaseOffer> = Map::new("lease_offers");
+pub const USERS: Map<&str, UserInfo> = Map::new("users");
+// Guardian and emergency pauses
+pub const PAUSE: Pausable = Pausable::new("guardian", "emergency_paused", "paused_operations");
@@ -0,0 +1,299 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier};
+use cosmwasm_std::{coin, coins, from_json, BankMsg, Env, MemoryStorage, OwnedDeps};
+use cw721::{Expiration, OwnerOfResponse};
+use cw721_base::msg::{ExecuteMsg as Cw721ExecuteMsg, InstantiateMsg as Cw721InstantiateMsg};
+use cw721_base::MintMsg;
+use rwa_stockholm_packages::msg::rwa_nft::{ExecuteMsg, InstantiateMsg, QueryMsg, UserOfResponse};
+
+use crate::contract::{execute, instantiate, query};
+use crate::ContractError;
+
+const MINTER: &str = "minter";
+const GUARDIAN: &str = "guardian";
+const ALICE: &str = "alice";
+const BOB: &str = "bob";
+
+type Deps = OwnedDeps<MemoryStorage, MockApi, MockQuerier>;
+
+/// A collection with token "1" minted to Alice.
+fn setup() -> Deps {
+    let mut deps = mock_dependencies();
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(MINTER, &[]),
+        InstantiateMsg {
+            cw721_base_msg: Cw721InstantiateMsg {
+                name: "Stockholm RWA".to_string(),
+                symbol: "RWA".to_string(),
+                minter: MINTER.to_string(),
+            },
+            guardian: GUARDIAN.to_string(),
+        },
+    )
+    .unwrap();
+    let mint = ExecuteMsg::Cw721Base(Cw721ExecuteMsg::Mint(MintMsg {
+        token_id: "1".to_string(),
+        owner: ALICE.to_string(),
+        token_uri: None,
+        extension: None,
+    }));
+    execute(deps.as_mut(), mock_env(), mock_info(MINTER, &[]), mint).unwrap();
+    deps
+}
+
+#[test]
+fn unknown_listings_and_lease_offers_are_typed_errors() {
+    let deps = setup();
+
+    let listing = QueryMsg::Listing {
+        token_id: "1".to_string(),
+    };
+    let err = query(deps.as_ref(), mock_env(), listing).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ListingNotFound {
+            token_id: "1".to_string()
+        }
+    );
+    let offer = QueryMsg::LeaseOffer {
+        token_id: "1".to_string(),
+    };
+    let err = query(deps.as_ref(), mock_env(), offer).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::LeaseOfferNotFound {
+            token_id: "1".to_string()
+        }
+    );
+}
+
+fn env_after(seconds: u64) -> Env {
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(seconds);
+    env
+}
+
+fn owner_of(deps: &Deps) -> String {
+    let msg = QueryMsg::OwnerOf {
+        token_id: "1".to_string(),
+        include_expired: None,
+    };
+    let response: OwnerOfResponse =
+        from_json(query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+    response.owner
+}
+
+fn user_of(deps: &Deps, env: Env) -> Option<String> {
+    let msg = QueryMsg::UserOf {
+        token_id: "1".to_string(),
+    };
+    let response: UserOfResponse = from_json(query(deps.as_ref(), env, msg).unwrap()).unwrap();
+    response.user
+}
+
+fn transfer_to_bob() -> ExecuteMsg {
+    ExecuteMsg::Cw721Base(Cw721ExecuteMsg::TransferNft {
+        recipient: BOB.to_string(),
+        token_id: "1".to_string(),
+    })
+}
+
+#[test]
+fn sales_take_exactly_the_listed_price() {
+    let mut deps = setup();
+    let list = ExecuteMsg::ListNftForSale {
+        token_id: "1".to_string(),
+        price: coin(100, "uom"),
+    };
+    execute(deps.as_mut(), mock_env(), mock_info(ALICE, &[]), list).unwrap();
+
+    let buy = ExecuteMsg::BuyNft {
+        token_id: "1".to_string(),
+    };
+    for funds in [coins(99, "uom"), coins(101, "uom"), coins(100, "uusdc")] {
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(BOB, &funds),
+            buy.clone(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::IncorrectPayment {
+                expected: "100uom".to_string()
+            }
+        );
+    }
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(BOB, &coins(100, "uom")),
+        buy,
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages[0].msg,
+        BankMsg::Send {
+            to_address: ALICE.to_string(),
+            amount: coins(100, "uom"),
+        }
+        .into()
+    );
+    assert_eq!(owner_of(&deps), BOB);
+}
+
+#[test]
+fn granted_user_role_must_expire_and_can_be_revoked() {
+    let mut deps = setup();
+    let set_user = |expires| ExecuteMsg::SetUser {
+        token_id: "1".to_string(),
+        user: BOB.to_string(),
+        expires,
+    };
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ALICE, &[]),
+        set_user(Expiration::Never {}),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::InvalidLeaseTerms { .. }));
+
+    let expires = Expiration::AtTime(mock_env().block.time.plus_seconds(3600));
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ALICE, &[]),
+        set_user(expires),
+    )
+    .unwrap();
+    assert_eq!(user_of(&deps, mock_env()), Some(BOB.to_string()));
+    assert_eq!(user_of(&deps, env_after(3600)), None);
+
+    let revoke = ExecuteMsg::RevokeUser {
+        token_id: "1".to_string(),
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(BOB, &[]),
+        revoke.clone(),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::Cw721(_)));
+    execute(deps.as_mut(), mock_env(), mock_info(ALICE, &[]), revoke).unwrap();
+    assert_eq!(user_of(&deps, mock_env()), None);
+}
+
+#[test]
+fn rented_lease_runs_its_full_term() {
+    let mut deps = setup();
+    let offer = ExecuteMsg::OfferLease {
+        token_id: "1".to_string(),
+        rent_per_period: coin(10, "uom"),
+        period_seconds: 3600,
+        max_periods: 3,
+    };
+    execute(deps.as_mut(), mock_env(), mock_info(ALICE, &[]), offer).unwrap();
+
+    let rent = |periods| ExecuteMsg::RentNft {
+        token_id: "1".to_string(),
+        periods,
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(BOB, &coins(40, "uom")),
+        rent(4),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::InvalidLeaseTerms { .. }));
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(BOB, &coins(10, "uom")),
+        rent(2),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::IncorrectPayment { .. }));
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(BOB, &coins(20, "uom")),
+        rent(2),
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages[0].msg,
+        BankMsg::Send {
+            to_address: ALICE.to_string(),
+            amount: coins(20, "uom"),
+        }
+        .into()
+    );
+    assert_eq!(user_of(&deps, env_after(7199)), Some(BOB.to_string()));
+
+    // Neither revoking nor moving the token cuts a paid lease short
+    let revoke = ExecuteMsg::RevokeUser {
+        token_id: "1".to_string(),
+    };
+    let err = execute(deps.as_mut(), mock_env(), mock_info(ALICE, &[]), revoke).unwrap_err();
+    assert!(matches!(err, ContractError::LeaseActive { .. }));
+    let err = execute(
+        deps.as_mut(),
+        env_after(7199),
+        mock_info(ALICE, &[]),
+        transfer_to_bob(),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::LeaseActive { .. }));
+
+    assert_eq!(user_of(&deps, env_after(7200)), None);
+    execute(
+        deps.as_mut(),
+        env_after(7200),
+        mock_info(ALICE, &[]),
+        transfer_to_bob(),
+    )
+    .unwrap();
+    assert_eq!(owner_of(&deps), BOB);
+}
+
+#[test]
+fn emergency_pause_stops_trading_but_not_transfers() {
+    let mut deps = setup();
+    let list = |token_id: &str| ExecuteMsg::ListNftForSale {
+        token_id: token_id.to_string(),
+        price: coin(100, "uom"),
+    };
+    execute(deps.as_mut(), mock_env(), mock_info(ALICE, &[]), list("1")).unwrap();
+    let pause = ExecuteMsg::EmergencyPause { operation: None };
+    execute(deps.as_mut(), mock_env(), mock_info(GUARDIAN, &[]), pause).unwrap();
+
+    let err = execute(deps.as_mut(), mock_env(), mock_info(ALICE, &[]), list("1")).unwrap_err();
+    assert!(matches!(err, ContractError::OperationPaused { .. }));
+    let buy = ExecuteMsg::BuyNft {
+        token_id: "1".to_string(),
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(BOB, &coins(100, "uom")),
+        buy,
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::OperationPaused { .. }));
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(ALICE, &[]),
+        transfer_to_bob(),
+    )
+    .unwrap();
+    assert_eq!(owner_of(&deps), BOB);
+}
@@ -0,0 +1,331 @@
+// This contract will allow users to stake their RWAs represented as NFTs (assuming a CW721-compatible standard)
+// and earn "OM" tokens (assuming a CW20-compatible standard) over time based on the staking period and the
+// value of the staked asset.
+
+use cosmwasm_std::{
+    entry_point, to_json_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response,
+    StdResult, Storage, Uint128, WasmMsg,
+};
+use cw2::set_contract_version;
+use cw20::Cw20ExecuteMsg;
+use cw721::{Cw721ExecuteMsg, Cw721QueryMsg, Cw721ReceiveMsg, OwnerOfResponse};
+use rwa_stockholm_packages::msg::staking::{
+    ConfigResponse, ExecuteMsg, InstantiateMsg, MigrateMsg, PendingRewardsResponse, QueryMsg,
+    StakedAssetResponse, SudoMsg,
+};
+use rwa_stockholm_packages::pause::Operation;
+
+use crate::state::{Config, StakedAsset, CONFIG, PAUSE, STAKED_ASSETS, TOTAL_STAKED};
+use crate::ContractError;
+
+const CONTRACT_NAME: &str = "rwa-staking";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+const DAY_SECONDS: u64 = 86_400;
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let config = Config {
+        om_token_address: deps.api.addr_validate(&msg.om_token_address)?,
+        reward_rate_per_day: msg.reward_rate_per_day,
+    };
+    CONFIG.save(deps.storage, &config)?;
+    TOTAL_STAKED.save(deps.storage, &0)?;
+    PAUSE.initialize(deps.storage, deps.api, &msg.guardian)?;
+
+    Ok(Response::new().add_attribute("method", "instantiate"))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    if let Some(operation) = operation(&msg) {
+        PAUSE.assert_not_paused(deps.storage, operation)?;
+    }
+    match msg {
+        ExecuteMsg::StakeNft {
+            nft_contract_address,
+            token_id,
+        } => pull_and_stake_nft(deps, env, info, nft_contract_address, token_id),
+        ExecuteMsg::UnstakeNft { token_id } => unstake_nft(deps, env, info, token_id),
+        ExecuteMsg::ClaimRewards { token_id } => claim_rewards(deps, env, info, token_id),
+        ExecuteMsg::ReceiveNft(msg) => receive_nft(deps, env, info, msg),
+        ExecuteMsg::EmergencyPause { operation } => {
+            PAUSE.set_paused(deps.storage, &info.sender, operation, true)
+        }
+        ExecuteMsg::EmergencyUnpause { operation } => {
+            PAUSE.set_paused(deps.storage, &info.sender, operation, false)
+        }
+        ExecuteMsg::SetGuardian { guardian } => {
+            PAUSE.set_guardian(deps.storage, deps.api, &info.sender, &guardian)
+        }
+    }
+}
+
+// Emergency pause covering `msg`, if any. Unstaking is never paused so staked NFTs can always
+// be taken back.
+fn operation(msg: &ExecuteMsg) -> Option<Operation> {
+    match msg {
+        ExecuteMsg::StakeNft { .. } | ExecuteMsg::ReceiveNft(_) => Some(Operation::Stake),
+        ExecuteMsg::ClaimRewards { .. } => Some(Operation::Claim),
+        _ => None,
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
+    let binary = match msg {
+        QueryMsg::Config {} => to_json_binary(&query_config(deps)?),
+        QueryMsg::StakedAsset { owner, token_id } => {
+            to_json_binary(&query_staked_asset(deps, owner, token_id)?)
+        }
+        QueryMsg::PendingRewards { owner, token_id } => {
+            to_json_binary(&query_pending_rewards(deps, env, owner, token_id)?)
+        }
+        QueryMsg::PauseInfo {} => to_json_binary(&PAUSE.query(deps.storage)?),
+    };
+    Ok(binary?)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::new().add_attribute("method", "migrate"))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn sudo(deps: DepsMut, _env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
+    match msg {
+        SudoMsg::UpdateRewardRate {
+            reward_rate_per_day,
+        } => {
+            CONFIG.update(deps.storage, |mut config| -> Result<_, ContractError> {
+                config.reward_rate_per_day = reward_rate_per_day;
+                Ok(config)
+            })?;
+
+            Ok(Response::new()
+                .add_attribute("action", "sudo_update_reward_rate")
+                .add_attribute("reward_rate_per_day", reward_rate_per_day.to_string()))
+        }
+    }
+}
+
+fn pull_and_stake_nft(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    nft_contract_address: String,
+    token_id: String,
+) -> Result<Response, ContractError> {
+    let nft_contract = deps.api.addr_validate(&nft_contract_address)?;
+    let owner: OwnerOfResponse = deps.querier.query_wasm_smart(
+        &nft_contract,
+        &Cw721QueryMsg::OwnerOf {
+            token_id: token_id.clone(),
+            include_expired: None,
+        },
+    )?;
+    if owner.owner != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Staking only holds if the transfer into the contract goes through
+    let transfer_msg = WasmMsg::Execute {
+        contract_addr: nft_contract.to_string(),
+        msg: to_json_binary(&Cw721ExecuteMsg::TransferNft {
+            recipient: env.contract.address.to_string(),
+            token_id: token_id.clone(),
+        })?,
+        funds: vec![],
+    };
+    let response = stake_nft(deps, env, info.sender, nft_contract, token_id)?;
+    Ok(response.add_message(transfer_msg))
+}
+
+// Records a stake for an NFT the contract holds or is about to receive
+fn stake_nft(
+    deps: DepsMut,
+    env: Env,
+    staker: Addr,
+    nft_contract: Addr,
+    token_id: String,
+) -> Result<Response, ContractError> {
+    let key = (&staker, token_id.as_str());
+    if STAKED_ASSETS.has(deps.storage, key) {
+        return Err(ContractError::AlreadyStaked { token_id });
+    }
+
+    let staked_asset = StakedAsset {
+        owner: staker.to_string(),
+        nft_contract_address: nft_contract.to_string(),
+        token_id: token_id.clone(),
+        staked_since: env.block.time.seconds(),
+        claimed_until: 0,
+    };
+
+    STAKED_ASSETS.save(deps.storage, key, &staked_asset)?;
+    TOTAL_STAKED.update(deps.storage, |total| -> Result<_, ContractError> {
+        Ok(total + 1)
+    })?;
+
+    Ok(Response::new()
+        .add_attribute("action", "stake_nft")
+        .add_attribute("nft_contract_address", nft_contract)
+        .add_attribute("token_id", token_id)
+        .add_attribute("staker", staker))
+}
+
+fn unstake_nft(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    token_id: String,
+) -> Result<Response, ContractError> {
+    let staked_asset = load_staked_asset(deps.storage, &info.sender, token_id.clone())?;
+
+    STAKED_ASSETS.remove(deps.storage, (&info.sender, &token_id));
+    TOTAL_STAKED.update(deps.storage, |total| -> Result<_, ContractError> {
+        Ok(total.saturating_sub(1))
+    })?;
+
+    // Hands the NFT back to the staker
+    let transfer_msg = WasmMsg::Execute {
+        contract_addr: staked_asset.nft_contract_address,
+        msg: to_json_binary(&Cw721ExecuteMsg::TransferNft {
+            recipient: info.sender.to_string(),
+            token_id: token_id.clone(),
+        })?,
+        funds: vec![],
+    };
+
+    Ok(Response::new()
+        .add_message(transfer_msg)
+        .add_attribute("action", "unstake_nft")
+        .add_attribute("token_id", token_id))
+}
+
+fn claim_rewards(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+) -> Result<Response, ContractError> {
+    let mut staked_asset = load_staked_asset(deps.storage, &info.sender, token_id.clone())?;
+
+    let config = CONFIG.load(deps.storage)?;
+    let rewards = accrued_rewards(&config, &env, &staked_asset)?;
+    // Only whole days are paid, so the part of a day already accrued carries over
+    staked_asset.claimed_until =
+        rewards_since(&staked_asset) + unpaid_days(&env, &staked_asset) * DAY_SECONDS;
+    STAKED_ASSETS.save(deps.storage, (&info.sender, &token_id), &staked_asset)?;
+
+    let mut response = Response::new();
+    // Transfer OM tokens as rewards; CW20 tokens refuse zero transfers
+    if !rewards.is_zero() {
+        let om_transfer_msg = Cw20ExecuteMsg::Transfer {
+            recipient: info.sender.to_string(),
+            amount: rewards,
+        };
+
+        response = response.add_message(WasmMsg::Execute {
+            contract_addr: config.om_token_address.to_string(),
+            msg: to_json_binary(&om_transfer_msg)?,
+            funds: vec![],
+        });
+    }
+
+    Ok(response
+        .add_attribute("action", "claim_rewards")
+        .add_attribute("token_id", token_id)
+        .add_attribute("rewards", rewards.to_string()))
+}
+
+fn receive_nft(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: Cw721ReceiveMsg,
+) -> Result<Response, ContractError> {
+    // The NFT has already been transferred to this contract; info.sender is the NFT contract
+    // and msg.sender is the previous owner, who becomes the staker
+    let staker = deps.api.addr_validate(&msg.sender)?;
+    stake_nft(deps, env, staker, info.sender, msg.token_id)
+}
+
+fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    Ok(ConfigResponse {
+        om_token_address: config.om_token_address.to_string(),
+        reward_rate_per_day: config.reward_rate_per_day,
+        total_staked: TOTAL_STAKED.load(deps.storage)?,
+    })
+}
+
+fn query_staked_asset(
+    deps: Deps,
+    owner: String,
+    token_id: String,
+) -> Result<StakedAssetResponse, ContractError> {
+    let owner = deps.api.addr_validate(&owner)?;
+    let staked_asset = load_staked_asset(deps.storage, &owner, token_id)?;
+    Ok(StakedAssetResponse {
+        owner: staked_asset.owner,
+        nft_contract_address: staked_asset.nft_contract_address,
+        token_id: staked_asset.token_id,
+        staked_since: staked_asset.staked_since,
+    })
+}
+
+fn query_pending_rewards(
+    deps: Deps,
+    env: Env,
+    owner: String,
+    token_id: String,
+) -> Result<PendingRewardsResponse, ContractError> {
+    let owner = deps.api.addr_validate(&owner)?;
+    let config = CONFIG.load(deps.storage)?;
+    let staked_asset = load_staked_asset(deps.storage, &owner, token_id.clone())?;
+    Ok(PendingRewardsResponse {
+        rewards: accrued_rewards(&config, &env, &staked_asset)?,
+        token_id,
+    })
+}
+
+fn load_staked_asset(
+    storage: &dyn Storage,
+    owner: &Addr,
+    token_id: String,
+) -> Result<StakedAsset, ContractError> {
+    STAKED_ASSETS
+        .may_load(storage, (owner, &token_id))?
+        .ok_or(ContractError::NotStaked { token_id })
+}
+
+// Calculate rewards based on time staked since the last claim
+// This is a simplified calculation; real-world usage might consider asset value, dynamic rates, etc.
+fn accrued_rewards(config: &Config, env: &Env, staked_asset: &StakedAsset) -> StdResult<Uint128> {
+    Ok(config
+        .reward_rate_per_day
+        .checked_mul(Uint128::from(unpaid_days(env, staked_asset)))?)
+}
+
+// Whole days staked since rewards were last paid
+fn unpaid_days(env: &Env, staked_asset: &StakedAsset) -> u64 {
+    (env.block.time.seconds() - rewards_since(staked_asset)) / DAY_SECONDS
+}
+
+fn rewards_since(staked_asset: &StakedAsset) -> u64 {
+    staked_asset.staked_since.max(staked_asset.claimed_until)
+}
@@ -0,0 +1,29 @@
+use cosmwasm_std::{Addr, Uint128};
+use cw_storage_plus::{Item, Map};
+use rwa_stockholm_packages::pause::Pausable;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Config {
+    pub om_token_address: Addr,
+    pub reward_rate_per_day: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct StakedAsset {
+    pub owner: String,
+    pub nft_contract_address: String,
+    pub token_id: String,
+    pub staked_since: u64, // Unix timestamp
+    // Rewards are paid out up to here; zero until the first claim
+    #[serde(default)]
+    pub claimed_until: u64,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+// Keyed by (owner, token_id); the contract holds every staked NFT
+pub const STAKED_ASSETS: Map<(&Addr, &str), StakedAsset> = Map::new("staked_nfts");
+pub const TOTAL_STAKED: Item<u64> = Item::new("total_staked");
+// Guardian and emergency pauses
+pub const PAUSE: Pausable = Pausable::new("guardian", "emergency_paused", "paused_operations");
@@ -0,0 +1,254 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier};
+use cosmwasm_std::{
+    from_json, to_json_binary, Binary, ContractResult, CosmosMsg, Env, MemoryStorage, OwnedDeps,
+    SystemResult, Uint128, WasmMsg, WasmQuery,
+};
+use cw20::Cw20ExecuteMsg;
+use cw721::{Cw721ExecuteMsg, Cw721QueryMsg, Cw721ReceiveMsg, OwnerOfResponse};
+use rwa_stockholm_packages::msg::staking::{
+    ExecuteMsg, InstantiateMsg, QueryMsg, StakedAssetResponse,
+};
+
+use crate::contract::{execute, instantiate, query};
+use crate::ContractError;
+
+const OM_TOKEN: &str = "om_token";
+const NFT: &str = "nft";
+const GUARDIAN: &str = "guardian";
+const ALICE: &str = "alice";
+const BOB: &str = "bob";
+const DAY: u64 = 86_400;
+
+type Deps = OwnedDeps<MemoryStorage, MockApi, MockQuerier>;
+
+fn setup() -> Deps {
+    let mut deps = mock_dependencies();
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("creator", &[]),
+        InstantiateMsg {
+            om_token_address: OM_TOKEN.to_string(),
+            reward_rate_per_day: Uint128::new(10),
+            guardian: GUARDIAN.to_string(),
+        },
+    )
+    .unwrap();
+    // Alice owns every token of the NFT contract
+    deps.querier.update_wasm(|query| match query {
+        WasmQuery::Smart { contract_addr, msg } if contract_addr == NFT => {
+            match from_json(msg).unwrap() {
+                Cw721QueryMsg::OwnerOf { .. } => SystemResult::Ok(ContractResult::Ok(
+                    to_json_binary(&OwnerOfResponse {
+                        owner: ALICE.to_string(),
+                        approvals: vec![],
+                    })
+                    .unwrap(),
+                )),
+                other => panic!("unexpected NFT query {other:?}"),
+            }
+        }
+        other => panic!("unexpected query {other:?}"),
+    });
+    deps
+}
+
+fn env_after(seconds: u64) -> Env {
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(seconds);
+    env
+}
+
+fn stake(deps: &mut Deps, sender: &str, token_id: &str) -> Result<Vec<CosmosMsg>, ContractError> {
+    let msg = ExecuteMsg::StakeNft {
+        nft_contract_address: NFT.to_string(),
+        token_id: token_id.to_string(),
+    };
+    let response = execute(deps.as_mut(), mock_env(), mock_info(sender, &[]), msg)?;
+    Ok(response
+        .messages
+        .into_iter()
+        .map(|message| message.msg)
+        .collect())
+}
+
+fn receive(deps: &mut Deps, nft: &str, from: &str, token_id: &str) {
+    let msg = ExecuteMsg::ReceiveNft(Cw721ReceiveMsg {
+        sender: from.to_string(),
+        token_id: token_id.to_string(),
+        msg: Binary::default(),
+    });
+    execute(deps.as_mut(), mock_env(), mock_info(nft, &[]), msg).unwrap();
+}
+
+fn nft_transfer(nft: &str, recipient: &str, token_id: &str) -> CosmosMsg {
+    CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: nft.to_string(),
+        msg: to_json_binary(&Cw721ExecuteMsg::TransferNft {
+            recipient: recipient.to_string(),
+            token_id: token_id.to_string(),
+        })
+        .unwrap(),
+        funds: vec![],
+    })
+}
+
+fn staked_asset(deps: &Deps, owner: &str, token_id: &str) -> StakedAssetResponse {
+    let msg = QueryMsg::StakedAsset {
+        owner: owner.to_string(),
+        token_id: token_id.to_string(),
+    };
+    from_json(query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap()
+}
+
+/// OM paid out by a claim at `env`.
+fn claim(deps: &mut Deps, env: Env, token_id: &str) -> Result<Uint128, ContractError> {
+    let msg = ExecuteMsg::ClaimRewards {
+        token_id: token_id.to_string(),
+    };
+    let response = execute(deps.as_mut(), env, mock_info(ALICE, &[]), msg)?;
+    let paid = response
+        .messages
+        .iter()
+        .find_map(|message| match &message.msg {
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr, msg, ..
+            }) if contract_addr == OM_TOKEN => Some(msg.clone()),
+            _ => None,
+        })
+        .map(|msg| match from_json(msg).unwrap() {
+            Cw20ExecuteMsg::Transfer { amount, .. } => amount,
+            other => panic!("unexpected OM message {other:?}"),
+        })
+        .unwrap_or_default();
+    Ok(paid)
+}
+
+#[test]
+fn rewards_are_only_paid_once() {
+    let mut deps = setup();
+    stake(&mut deps, ALICE, "1").unwrap();
+
+    let two_and_a_half_days = 2 * DAY + DAY / 2;
+    assert_eq!(
+        claim(&mut deps, env_after(two_and_a_half_days), "1").unwrap(),
+        Uint128::new(20)
+    );
+    assert_eq!(
+        claim(&mut deps, env_after(two_and_a_half_days), "1").unwrap(),
+        Uint128::zero()
+    );
+    // The half day left over from the first claim still counts
+    assert_eq!(
+        claim(&mut deps, env_after(3 * DAY), "1").unwrap(),
+        Uint128::new(10)
+    );
+}
+
+#[test]
+fn claim_needs_a_stake() {
+    let mut deps = setup();
+
+    let err = claim(&mut deps, mock_env(), "1").unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::NotStaked {
+            token_id: "1".to_string()
+        }
+    );
+}
+
+#[test]
+fn only_the_owner_stakes_an_nft_and_it_moves_into_the_contract() {
+    let mut deps = setup();
+
+    let err = stake(&mut deps, BOB, "1").unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let messages = stake(&mut deps, ALICE, "1").unwrap();
+    let contract = mock_env().contract.address;
+    assert_eq!(messages, vec![nft_transfer(NFT, contract.as_str(), "1")]);
+    let err = stake(&mut deps, ALICE, "1").unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::AlreadyStaked {
+            token_id: "1".to_string()
+        }
+    );
+}
+
+#[test]
+fn stakes_are_kept_per_owner() {
+    let mut deps = setup();
+    // Two collections can both have a token "1"
+    receive(&mut deps, NFT, ALICE, "1");
+    receive(&mut deps, "other_nft", BOB, "1");
+
+    assert_eq!(staked_asset(&deps, ALICE, "1").nft_contract_address, NFT);
+    assert_eq!(
+        staked_asset(&deps, BOB, "1").nft_contract_address,
+        "other_nft"
+    );
+}
+
+#[test]
+fn unstaking_returns_the_nft() {
+    let mut deps = setup();
+    receive(&mut deps, NFT, ALICE, "1");
+
+    let unstake = ExecuteMsg::UnstakeNft {
+        token_id: "1".to_string(),
+    };
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(BOB, &[]),
+        unstake.clone(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::NotStaked {
+            token_id: "1".to_string()
+        }
+    );
+
+    let response = execute(deps.as_mut(), mock_env(), mock_info(ALICE, &[]), unstake).unwrap();
+    assert_eq!(response.messages[0].msg, nft_transfer(NFT, ALICE, "1"));
+}
+
+#[test]
+fn unknown_stakes_are_typed_errors() {
+    let deps = setup();
+
+    let msg = QueryMsg::PendingRewards {
+        owner: ALICE.to_string(),
+        token_id: "1".to_string(),
+    };
+    let err = query(deps.as_ref(), mock_env(), msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::NotStaked {
+            token_id: "1".to_string()
+        }
+    );
+}
+
+#[test]
+fn emergency_pause_stops_staking_and_claims_but_not_unstaking() {
+    let mut deps = setup();
+    receive(&mut deps, NFT, ALICE, "1");
+    let pause = ExecuteMsg::EmergencyPause { operation: None };
+    execute(deps.as_mut(), mock_env(), mock_info(GUARDIAN, &[]), pause).unwrap();
+
+    let err = stake(&mut deps, ALICE, "2").unwrap_err();
+    assert!(matches!(err, ContractError::OperationPaused { .. }));
+    let err = claim(&mut deps, env_after(DAY), "1").unwrap_err();
+    assert!(matches!(err, ContractError::OperationPaused { .. }));
+
+    let unstake = ExecuteMsg::UnstakeNft {
+        token_id: "1".to_string(),
+    };
+    let response = execute(deps.as_mut(), mock_env(), mock_info(ALICE, &[]), unstake).unwrap();
+    assert_eq!(response.messages[0].msg, nft_transfer(NFT, ALICE, "1"));
+}
@@ -0,0 +1,197 @@
+use cosmwasm_std::{
+    CheckedMultiplyFractionError, ConversionOverflowError, Decimal, OverflowError, StdError,
+    Timestamp, Uint128,
+};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    Overflow(#[from] OverflowError),
+
+    #[error("{0}")]
+    ConversionOverflow(#[from] ConversionOverflowError),
+
+    #[error("{0}")]
+    CheckedMultiplyFraction(#[from] CheckedMultiplyFractionError),
+
+    #[error("{0}")]
+    Cw721(#[from] cw721_base::ContractError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("No admin transfer has been proposed")]
+    NoPendingAdmin {},
+
+    #[error("Contract is paused")]
+    Paused {},
+
+    #[error("The {operation} operation is paused")]
+    OperationPaused { operation: String },
+
+    #[error("Expired")]
+    Expired {},
+
+    #[error("Insufficient funds: expected {expected}")]
+    InsufficientFunds { expected: String },
+
+    #[error("Payment must be exactly {expected}")]
+    IncorrectPayment { expected: String },
+
+    // gold_swap
+    #[error("No trading pair for token {token}")]
+    PairNotFound { token: String },
+
+    #[error("A trading pair for {token} already exists")]
+    PairExists { token: String },
+
+    #[error("Trading pair for {token} is disabled")]
+    PairDisabled { token: String },
+
+    #[error("Invalid asset: {reason}")]
+    InvalidAsset { reason: String },
+
+    #[error("Exchange rate has not been set")]
+    RateNotSet {},
+
+    #[error("Token {token} is not accepted by this contract")]
+    InvalidToken { token: String },
+
+    #[error("Slippage exceeded: expected at least {min_output}, got {output}")]
+    SlippageExceeded {
+        min_output: Uint128,
+        output: Uint128,
+    },
+
+    #[error("Invalid swap params: {reason}")]
+    InvalidSwapParams { reason: String },
+
+    #[error("Oracle price is {age_seconds}s old, older than the allowed {max_staleness_seconds}s")]
+    StalePrice {
+        age_seconds: u64,
+        max_staleness_seconds: u64,
+    },
+
+    #[error("Oracle price {price} is too uncertain: confidence band of {confidence}")]
+    PriceUncertain { price: Decimal, confidence: Decimal },
+
+    #[error("Only {submissions} agreeing price submissions this round, quorum is {quorum}")]
+    QuorumNotMet { submissions: u32, quorum: u32 },
+
+    #[error("Invalid price submission: {reason}")]
+    InvalidPriceSubmission { reason: String },
+
+    #[error("Invalid rate source: {reason}")]
+    InvalidRateSource { reason: String },
+
+    #[error("Rate change from {previous} to {proposed} exceeds {max_change_bps} bps")]
+    RateChangeTooLarge {
+        previous: Decimal,
+        proposed: Decimal,
+        max_change_bps: u16,
+    },
+
+    #[error("Rate changes on this pair must be announced at least {delay_seconds}s ahead")]
+    RateTimelocked { delay_seconds: u64 },
+
+    #[error("No trade with id {id}")]
+    TradeNotFound { id: u64 },
+
+    #[error("No open order with id {id}")]
+    OrderNotFound { id: u64 },
+
+    #[error("Invalid limit order: {reason}")]
+    InvalidOrder { reason: String },
+
+    #[error("No DCA plan with id {id}")]
+    DcaPlanNotFound { id: u64 },
+
+    #[error("Invalid DCA plan: {reason}")]
+    InvalidDcaPlan { reason: String },
+
+    #[error("No rate change is pending for {token}")]
+    NoPendingRate { token: String },
+
+    #[error("No pair update is pending for {token}")]
+    NoPendingPairUpdate { token: String },
+
+    #[error("The pending pair update takes effect at {effective_at}")]
+    PairUpdateNotDue { effective_at: Timestamp },
+
+    #[error("Rate may change at most {max_updates} times per {window_seconds}s")]
+    TooManyRateUpdates {
+        max_updates: u32,
+        window_seconds: u64,
+    },
+
+    #[error("Swap exceeds the {limit} volume limit, remaining allowance is {remaining}")]
+    VolumeLimitExceeded { limit: String, remaining: Uint128 },
+
+    #[error("Invalid volume limits: {reason}")]
+    InvalidVolumeLimits { reason: String },
+
+    #[error("Insufficient {token} reserve: requested {requested}, available {available}")]
+    InsufficientReserve {
+        token: String,
+        requested: Uint128,
+        available: Uint128,
+    },
+
+    #[error("Invalid attestation: {reason}")]
+    InvalidAttestation { reason: String },
+
+    #[error("No reserve attestation has been posted for {token}")]
+    AttestationMissing { token: String },
+
+    #[error("Reserve attestation for {token} is {age_seconds}s old, older than the allowed {max_age_seconds}s")]
+    StaleAttestation {
+        token: String,
+        age_seconds: u64,
+        max_age_seconds: u64,
+    },
+
+    #[error("Circulating supply of {token} ({supply}) exceeds attested reserves ({backed})")]
+    UnbackedSupply {
+        token: String,
+        supply: Uint128,
+        backed: Uint128,
+    },
+
+    // liquidity_pool
+    #[error("Insufficient pool balance: requested {requested}, available {available}")]
+    InsufficientPoolBalance {
+        requested: Uint128,
+        available: Uint128,
+    },
+
+    // staking
+    #[error("Token {token_id} is not staked")]
+    NotStaked { token_id: String },
+
+    #[error("Token {token_id} is already staked")]
+    AlreadyStaked { token_id: String },
+
+    // rwa_nft
+    #[error("Token {token_id} is not listed for sale")]
+    ListingNotFound { token_id: String },
+
+    #[error("Token {token_id} is not offered for lease")]
+    LeaseOfferNotFound { token_id: String },
+
+    #[error("Token {token_id} is leased to {user} until {expires}")]
+    LeaseActive {
+        token_id: String,
+        user: String,
+        expires: String,
+    },
+
+    #[error("Token {token_id} has no user")]
+    UserNotFound { token_id: String },
+
+    #[error("Invalid lease terms: {reason}")]
+    InvalidLeaseTerms { reason: String },
+}
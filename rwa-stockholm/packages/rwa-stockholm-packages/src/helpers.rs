@@ -0,0 +1,523 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, Coin, CosmosMsg, Empty, QuerierWrapper, QueryRequest, StdResult,
+    Timestamp, Uint128, WasmMsg, WasmQuery,
+};
+use cw20::Cw20ExecuteMsg;
+use cw721::{Cw721ExecuteMsg, OwnerOfResponse};
+use serde::Serialize;
+
+use crate::msg::{gold_swap, liquidity_pool, rwa_nft, staking};
+
+/// RwaNftContract is a wrapper around Addr that provides a lot of helpers
+/// for working with the rwa-nft contract.
+#[cw_serde]
+pub struct RwaNftContract(pub Addr);
+
+impl RwaNftContract {
+    pub fn addr(&self) -> Addr {
+        self.0.clone()
+    }
+
+    pub fn call<T: Into<rwa_nft::ExecuteMsg>>(&self, msg: T) -> StdResult<CosmosMsg> {
+        self.call_with_funds(msg, vec![])
+    }
+
+    /// Used for the paid messages, `BuyNft` and `RentNft`.
+    pub fn call_with_funds<T: Into<rwa_nft::ExecuteMsg>>(
+        &self,
+        msg: T,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg> {
+        execute_msg(&self.0, &msg.into(), funds)
+    }
+
+    pub fn query_request(&self, msg: &rwa_nft::QueryMsg) -> StdResult<QueryRequest<Empty>> {
+        query_request(&self.0, msg)
+    }
+
+    pub fn owner_of(
+        &self,
+        querier: &QuerierWrapper,
+        token_id: impl Into<String>,
+    ) -> StdResult<OwnerOfResponse> {
+        querier.query(&self.query_request(&rwa_nft::QueryMsg::OwnerOf {
+            token_id: token_id.into(),
+            include_expired: None,
+        })?)
+    }
+
+    pub fn pause_info(
+        &self,
+        querier: &QuerierWrapper,
+    ) -> StdResult<crate::pause::PauseInfoResponse> {
+        querier.query(&self.query_request(&rwa_nft::QueryMsg::PauseInfo {})?)
+    }
+
+    pub fn listing(
+        &self,
+        querier: &QuerierWrapper,
+        token_id: impl Into<String>,
+    ) -> StdResult<rwa_nft::ListingResponse> {
+        querier.query(&self.query_request(&rwa_nft::QueryMsg::Listing {
+            token_id: token_id.into(),
+        })?)
+    }
+
+    pub fn user_of(
+        &self,
+        querier: &QuerierWrapper,
+        token_id: impl Into<String>,
+    ) -> StdResult<rwa_nft::UserOfResponse> {
+        querier.query(&self.query_request(&rwa_nft::QueryMsg::UserOf {
+            token_id: token_id.into(),
+        })?)
+    }
+
+    pub fn lease_offer(
+        &self,
+        querier: &QuerierWrapper,
+        token_id: impl Into<String>,
+    ) -> StdResult<rwa_nft::LeaseOfferResponse> {
+        querier.query(&self.query_request(&rwa_nft::QueryMsg::LeaseOffer {
+            token_id: token_id.into(),
+        })?)
+    }
+}
+
+/// GoldSwapContract is a wrapper around Addr that provides a lot of helpers
+/// for working with the gold-swap contract.
+#[cw_serde]
+pub struct GoldSwapContract(pub Addr);
+
+impl GoldSwapContract {
+    pub fn addr(&self) -> Addr {
+        self.0.clone()
+    }
+
+    pub fn call<T: Into<gold_swap::ExecuteMsg>>(&self, msg: T) -> StdResult<CosmosMsg> {
+        self.call_with_funds(msg, vec![])
+    }
+
+    /// Used for the messages paid in native coins, `Swap` and `FundReserve`.
+    pub fn call_with_funds<T: Into<gold_swap::ExecuteMsg>>(
+        &self,
+        msg: T,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg> {
+        execute_msg(&self.0, &msg.into(), funds)
+    }
+
+    /// Builds the CW20 `Send` of `amount` tokens from `token` to this contract,
+    /// with `msg` embedded as the hook payload.
+    pub fn send_cw20(
+        &self,
+        token: impl Into<String>,
+        amount: Uint128,
+        msg: &gold_swap::ReceiveMsg,
+    ) -> StdResult<CosmosMsg> {
+        send_cw20(&self.0, token, amount, to_json_binary(msg)?)
+    }
+
+    pub fn query_request(&self, msg: &gold_swap::QueryMsg) -> StdResult<QueryRequest<Empty>> {
+        query_request(&self.0, msg)
+    }
+
+    pub fn config(&self, querier: &QuerierWrapper) -> StdResult<gold_swap::ConfigResponse> {
+        querier.query(&self.query_request(&gold_swap::QueryMsg::Config {})?)
+    }
+
+    pub fn ownership(
+        &self,
+        querier: &QuerierWrapper,
+    ) -> StdResult<crate::ownership::OwnershipResponse> {
+        querier.query(&self.query_request(&gold_swap::QueryMsg::Ownership {})?)
+    }
+
+    pub fn pause_info(
+        &self,
+        querier: &QuerierWrapper,
+    ) -> StdResult<crate::pause::PauseInfoResponse> {
+        querier.query(&self.query_request(&gold_swap::QueryMsg::PauseInfo {})?)
+    }
+
+    pub fn pair(
+        &self,
+        querier: &QuerierWrapper,
+        base_token: impl Into<String>,
+    ) -> StdResult<gold_swap::PairResponse> {
+        querier.query(&self.query_request(&gold_swap::QueryMsg::Pair {
+            base_token: base_token.into(),
+        })?)
+    }
+
+    pub fn pairs(
+        &self,
+        querier: &QuerierWrapper,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<gold_swap::PairsResponse> {
+        querier.query(&self.query_request(&gold_swap::QueryMsg::Pairs { start_after, limit })?)
+    }
+
+    pub fn exchange_rate(
+        &self,
+        querier: &QuerierWrapper,
+        base_token: impl Into<String>,
+    ) -> StdResult<gold_swap::ExchangeRateResponse> {
+        querier.query(&self.query_request(&gold_swap::QueryMsg::ExchangeRate {
+            base_token: base_token.into(),
+        })?)
+    }
+
+    pub fn pending_rate(
+        &self,
+        querier: &QuerierWrapper,
+    ) -> StdResult<gold_swap::PendingRateResponse> {
+        querier.query(&self.query_request(&gold_swap::QueryMsg::PendingRate {})?)
+    }
+
+    pub fn pending_pair_update(
+        &self,
+        querier: &QuerierWrapper,
+        base_token: impl Into<String>,
+    ) -> StdResult<Option<gold_swap::PendingPairUpdate>> {
+        querier.query(
+            &self.query_request(&gold_swap::QueryMsg::PendingPairUpdate {
+                base_token: base_token.into(),
+            })?,
+        )
+    }
+
+    pub fn rate_history(
+        &self,
+        querier: &QuerierWrapper,
+        base_token: impl Into<String>,
+        start: Option<Timestamp>,
+        end: Option<Timestamp>,
+        limit: Option<u32>,
+    ) -> StdResult<gold_swap::RateHistoryResponse> {
+        querier.query(&self.query_request(&gold_swap::QueryMsg::RateHistory {
+            base_token: base_token.into(),
+            start,
+            end,
+            limit,
+        })?)
+    }
+
+    pub fn twap(
+        &self,
+        querier: &QuerierWrapper,
+        base_token: impl Into<String>,
+        window_seconds: u64,
+    ) -> StdResult<gold_swap::TwapResponse> {
+        querier.query(&self.query_request(&gold_swap::QueryMsg::Twap {
+            base_token: base_token.into(),
+            window_seconds,
+        })?)
+    }
+
+    /// `ask_asset` is only needed when offering a quote token.
+    pub fn simulation(
+        &self,
+        querier: &QuerierWrapper,
+        offer_asset: impl Into<String>,
+        ask_asset: Option<String>,
+        amount: Uint128,
+    ) -> StdResult<gold_swap::SimulationResponse> {
+        querier.query(&self.query_request(&gold_swap::QueryMsg::Simulation {
+            offer_asset: offer_asset.into(),
+            ask_asset,
+            amount,
+        })?)
+    }
+
+    /// `offer_asset` is only needed when asking for a quote token.
+    pub fn reverse_simulation(
+        &self,
+        querier: &QuerierWrapper,
+        ask_asset: impl Into<String>,
+        offer_asset: Option<String>,
+        amount: Uint128,
+    ) -> StdResult<gold_swap::ReverseSimulationResponse> {
+        querier.query(
+            &self.query_request(&gold_swap::QueryMsg::ReverseSimulation {
+                ask_asset: ask_asset.into(),
+                offer_asset,
+                amount,
+            })?,
+        )
+    }
+
+    pub fn feeders(
+        &self,
+        querier: &QuerierWrapper,
+        base_token: impl Into<String>,
+    ) -> StdResult<gold_swap::FeedersResponse> {
+        querier.query(&self.query_request(&gold_swap::QueryMsg::Feeders {
+            base_token: base_token.into(),
+        })?)
+    }
+
+    pub fn reserve_status(
+        &self,
+        querier: &QuerierWrapper,
+    ) -> StdResult<gold_swap::ReserveStatusResponse> {
+        querier.query(&self.query_request(&gold_swap::QueryMsg::ReserveStatus {})?)
+    }
+
+    pub fn trade(&self, querier: &QuerierWrapper, id: u64) -> StdResult<gold_swap::Trade> {
+        querier.query(&self.query_request(&gold_swap::QueryMsg::Trade { id })?)
+    }
+
+    pub fn trades_by_user(
+        &self,
+        querier: &QuerierWrapper,
+        user: impl Into<String>,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> StdResult<gold_swap::TradesResponse> {
+        querier.query(&self.query_request(&gold_swap::QueryMsg::TradesByUser {
+            user: user.into(),
+            start_after,
+            limit,
+        })?)
+    }
+
+    pub fn order(&self, querier: &QuerierWrapper, id: u64) -> StdResult<gold_swap::LimitOrder> {
+        querier.query(&self.query_request(&gold_swap::QueryMsg::Order { id })?)
+    }
+
+    pub fn order_book(
+        &self,
+        querier: &QuerierWrapper,
+        base_token: impl Into<String>,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> StdResult<gold_swap::OrdersResponse> {
+        querier.query(&self.query_request(&gold_swap::QueryMsg::OrderBook {
+            base_token: base_token.into(),
+            start_after,
+            limit,
+        })?)
+    }
+
+    pub fn orders_by_user(
+        &self,
+        querier: &QuerierWrapper,
+        user: impl Into<String>,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> StdResult<gold_swap::OrdersResponse> {
+        querier.query(&self.query_request(&gold_swap::QueryMsg::OrdersByUser {
+            user: user.into(),
+            start_after,
+            limit,
+        })?)
+    }
+
+    pub fn dca_plan(&self, querier: &QuerierWrapper, id: u64) -> StdResult<gold_swap::DcaPlan> {
+        querier.query(&self.query_request(&gold_swap::QueryMsg::DcaPlan { id })?)
+    }
+
+    pub fn dca_plans_by_user(
+        &self,
+        querier: &QuerierWrapper,
+        user: impl Into<String>,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> StdResult<gold_swap::DcaPlansResponse> {
+        querier.query(&self.query_request(&gold_swap::QueryMsg::DcaPlansByUser {
+            user: user.into(),
+            start_after,
+            limit,
+        })?)
+    }
+
+    pub fn collected_fees(
+        &self,
+        querier: &QuerierWrapper,
+    ) -> StdResult<gold_swap::CollectedFeesResponse> {
+        querier.query(&self.query_request(&gold_swap::QueryMsg::CollectedFees {})?)
+    }
+
+    pub fn reserves(&self, querier: &QuerierWrapper) -> StdResult<gold_swap::ReservesResponse> {
+        querier.query(&self.query_request(&gold_swap::QueryMsg::Reserves {})?)
+    }
+
+    pub fn remaining_allowance(
+        &self,
+        querier: &QuerierWrapper,
+        base_token: impl Into<String>,
+        address: impl Into<String>,
+    ) -> StdResult<gold_swap::RemainingAllowanceResponse> {
+        querier.query(
+            &self.query_request(&gold_swap::QueryMsg::RemainingAllowance {
+                base_token: base_token.into(),
+                address: address.into(),
+            })?,
+        )
+    }
+}
+
+/// LiquidityPoolContract is a wrapper around Addr that provides a lot of helpers
+/// for working with the liquidity-pool contract.
+#[cw_serde]
+pub struct LiquidityPoolContract(pub Addr);
+
+impl LiquidityPoolContract {
+    pub fn addr(&self) -> Addr {
+        self.0.clone()
+    }
+
+    pub fn call<T: Into<liquidity_pool::ExecuteMsg>>(&self, msg: T) -> StdResult<CosmosMsg> {
+        execute_msg(&self.0, &msg.into(), vec![])
+    }
+
+    /// Builds the CW20 `Send` that deposits `amount` OM from `token` into the pool.
+    pub fn send_cw20(&self, token: impl Into<String>, amount: Uint128) -> StdResult<CosmosMsg> {
+        let msg = to_json_binary(&liquidity_pool::ReceiveMsg::DepositOmToken {})?;
+        send_cw20(&self.0, token, amount, msg)
+    }
+
+    pub fn query_request(&self, msg: &liquidity_pool::QueryMsg) -> StdResult<QueryRequest<Empty>> {
+        query_request(&self.0, msg)
+    }
+
+    pub fn config(&self, querier: &QuerierWrapper) -> StdResult<liquidity_pool::ConfigResponse> {
+        querier.query(&self.query_request(&liquidity_pool::QueryMsg::Config {})?)
+    }
+
+    pub fn pause_info(
+        &self,
+        querier: &QuerierWrapper,
+    ) -> StdResult<crate::pause::PauseInfoResponse> {
+        querier.query(&self.query_request(&liquidity_pool::QueryMsg::PauseInfo {})?)
+    }
+
+    pub fn pool_info(
+        &self,
+        querier: &QuerierWrapper,
+    ) -> StdResult<liquidity_pool::PoolInfoResponse> {
+        querier.query(&self.query_request(&liquidity_pool::QueryMsg::PoolInfo {})?)
+    }
+
+    pub fn deposit(
+        &self,
+        querier: &QuerierWrapper,
+        address: impl Into<String>,
+    ) -> StdResult<liquidity_pool::DepositResponse> {
+        let msg = liquidity_pool::QueryMsg::Deposit {
+            address: address.into(),
+        };
+        querier.query(&self.query_request(&msg)?)
+    }
+}
+
+/// StakingContract is a wrapper around Addr that provides a lot of helpers
+/// for working with the staking contract.
+#[cw_serde]
+pub struct StakingContract(pub Addr);
+
+impl StakingContract {
+    pub fn addr(&self) -> Addr {
+        self.0.clone()
+    }
+
+    pub fn call<T: Into<staking::ExecuteMsg>>(&self, msg: T) -> StdResult<CosmosMsg> {
+        execute_msg(&self.0, &msg.into(), vec![])
+    }
+
+    /// Builds the cw721 `SendNft` that transfers `token_id` from `nft_contract`
+    /// into this contract and stakes it for the sender.
+    pub fn stake_nft(
+        &self,
+        nft_contract: impl Into<String>,
+        token_id: impl Into<String>,
+    ) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr: nft_contract.into(),
+            msg: to_json_binary(&Cw721ExecuteMsg::SendNft {
+                contract: self.0.to_string(),
+                token_id: token_id.into(),
+                msg: Binary::default(),
+            })?,
+            funds: vec![],
+        }
+        .into())
+    }
+
+    pub fn query_request(&self, msg: &staking::QueryMsg) -> StdResult<QueryRequest<Empty>> {
+        query_request(&self.0, msg)
+    }
+
+    pub fn config(&self, querier: &QuerierWrapper) -> StdResult<staking::ConfigResponse> {
+        querier.query(&self.query_request(&staking::QueryMsg::Config {})?)
+    }
+
+    pub fn pause_info(
+        &self,
+        querier: &QuerierWrapper,
+    ) -> StdResult<crate::pause::PauseInfoResponse> {
+        querier.query(&self.query_request(&staking::QueryMsg::PauseInfo {})?)
+    }
+
+    pub fn staked_asset(
+        &self,
+        querier: &QuerierWrapper,
+        owner: impl Into<String>,
+        token_id: impl Into<String>,
+    ) -> StdResult<staking::StakedAssetResponse> {
+        querier.query(&self.query_request(&staking::QueryMsg::StakedAsset {
+            owner: owner.into(),
+            token_id: token_id.into(),
+        })?)
+    }
+
+    pub fn pending_rewards(
+        &self,
+        querier: &QuerierWrapper,
+        owner: impl Into<String>,
+        token_id: impl Into<String>,
+    ) -> StdResult<staking::PendingRewardsResponse> {
+        querier.query(&self.query_request(&staking::QueryMsg::PendingRewards {
+            owner: owner.into(),
+            token_id: token_id.into(),
+        })?)
+    }
+}
+
+fn execute_msg<M: Serialize>(contract: &Addr, msg: &M, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+    Ok(WasmMsg::Execute {
+        contract_addr: contract.to_string(),
+        msg: to_json_binary(msg)?,
+        funds,
+    }
+    .into())
+}
+
+fn send_cw20(
+    contract: &Addr,
+    token: impl Into<String>,
+    amount: Uint128,
+    msg: Binary,
+) -> StdResult<CosmosMsg> {
+    Ok(WasmMsg::Execute {
+        contract_addr: token.into(),
+        msg: to_json_binary(&Cw20ExecuteMsg::Send {
+            contract: contract.to_string(),
+            amount,
+            msg,
+        })?,
+        funds: vec![],
+    }
+    .into())
+}
+
+fn query_request<M: Serialize>(contract: &Addr, msg: &M) -> StdResult<QueryRequest<Empty>> {
+    Ok(WasmQuery::Smart {
+        contract_addr: contract.to_string(),
+        msg: to_json_binary(msg)?,
+    }
+    .into())
+}
@@ -1,6 +1,6 @@
-pub mod contract;
 mod error;
 pub mod helpers;
 pub mod msg;
-pub mod state;
+pub mod ownership;
+pub mod pause;
 pub use crate::error::ContractError;
@@ -0,0 +1,660 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Decimal, Timestamp, Uint128};
+use cw20::Cw20ReceiveMsg;
+
+use crate::ownership::Role;
+use crate::pause::Operation;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub admin: String,
+    // Initial role holders, see `ownership::Role`; the admin can act in every role
+    pub rate_setter: Option<String>,
+    pub pauser: Option<String>,
+    pub treasury: Option<String>,
+    // Can pause the contract in an emergency, see `pause`
+    pub guardian: String,
+}
+
+// Either side of a pair: a bank denom (e.g. a token factory denom) or a CW20 contract
+#[cw_serde]
+pub enum AssetInfo {
+    Native { denom: String },
+    Cw20 { addr: String },
+}
+
+impl AssetInfo {
+    /// The denom or contract address, which is how messages and storage refer to the asset.
+    pub fn as_str(&self) -> &str {
+        match self {
+            AssetInfo::Native { denom } => denom,
+            AssetInfo::Cw20 { addr } => addr,
+        }
+    }
+}
+
+#[cw_serde]
+pub struct Asset {
+    pub info: AssetInfo,
+    pub amount: Uint128,
+}
+
+// A commodity token (gold, silver, carbon credits, ...) traded against a quote token such as OM.
+// Pairs are identified by their base token's denom or address; rates are whole quote tokens per
+// whole base token.
+#[cw_serde]
+pub struct PairConfig {
+    pub base_token: AssetInfo,
+    pub quote_token: AssetInfo,
+    // Required for native assets; CW20 decimals are read from the token's TokenInfo
+    pub base_decimals: Option<u8>,
+    pub quote_decimals: Option<u8>,
+    pub rate_source: RateSource,
+    pub sell: SwapParams, // Base to quote
+    pub buy: SwapParams,  // Quote to base, paid out at the inverse rate
+    // Protocol fee on every swap on top of the direction's own fee
+    pub swap_fee_bps: u16,
+    pub circuit_breaker: CircuitBreaker,
+    pub volume_limits: VolumeLimits,
+    // Minimum notice for `ProposeRate`; `SetExchangeRate` is only allowed while this is zero
+    pub rate_delay_seconds: u64,
+    // Share of each DCA swap paid to the keeper that runs it, at most 100 (1%)
+    pub keeper_tip_bps: u16,
+}
+
+// Changes to an existing pair; a `None` field is left unchanged
+#[cw_serde]
+#[derive(Default)]
+pub struct PairUpdate {
+    pub rate_source: Option<RateSource>,
+    pub sell: Option<SwapParams>,
+    pub buy: Option<SwapParams>,
+    pub swap_fee_bps: Option<u16>,
+    pub circuit_breaker: Option<CircuitBreaker>,
+    pub volume_limits: Option<VolumeLimits>,
+    pub rate_delay_seconds: Option<u64>,
+    pub keeper_tip_bps: Option<u16>,
+    pub enabled: Option<bool>,
+}
+
+// Where a pair's swaps take their rate from
+#[cw_serde]
+pub enum RateSource {
+    // The rate last set through `SetExchangeRate`
+    Fixed {},
+    // Queried from a price feed on every swap, see `msg::oracle`
+    Oracle {
+        oracle: String,
+        pair: String, // e.g. "XAU/OM"
+        max_staleness_seconds: u64,
+        max_confidence_bps: u16, // Widest accepted confidence band, relative to the price
+    },
+    // Median of the whitelisted feeders' `SubmitPrice` calls from the last `round_seconds`
+    Feeders {
+        quorum: u32, // Minimum number of fresh, non-outlier submissions
+        round_seconds: u64,
+        max_deviation_bps: u16, // Submissions further than this from the median are dropped
+    },
+}
+
+// Guardrails on rate changes; every limit is off when `None`
+#[cw_serde]
+#[derive(Default)]
+pub struct CircuitBreaker {
+    pub max_rate_change_bps: Option<u16>, // Largest move a single `SetExchangeRate` may make
+    pub max_updates_per_window: Option<u32>, // `SetExchangeRate` calls allowed per window
+    pub window_seconds: u64,
+    // Oracle or feeder move since the previous swap that pauses the pair until unpaused
+    pub auto_pause_bps: Option<u16>,
+}
+
+// Caps on swap volume, measured in base token units on either side of the swap.
+// Allowlisted market makers are exempt from both; a `None` cap is off. Both are rolling
+// windows, over the last day and the last `epoch_seconds`.
+#[cw_serde]
+#[derive(Default)]
+pub struct VolumeLimits {
+    pub per_address_daily: Option<Uint128>,
+    pub global_per_epoch: Option<Uint128>,
+    pub epoch_seconds: u64,
+}
+
+// Physical holdings a pair's base token must be backed by before it can be bought
+#[cw_serde]
+pub struct ReserveBacking {
+    pub tokens_per_ounce: Decimal, // Whole base tokens issued per attested troy ounce
+    // Oldest attestation swaps still accept; `None` accepts any age
+    pub max_attestation_age_seconds: Option<u64>,
+}
+
+// An auditor's statement of the vault holdings behind a pair's base token
+#[cw_serde]
+pub struct Attestation {
+    pub ounces: Decimal,
+    pub auditor: Addr,
+    pub report_hash: String, // Hash of the published audit report
+    pub reported_at: Timestamp,
+    pub posted_at: Timestamp,
+}
+
+// Deductions applied to the output of a swap in one direction, in basis points
+#[cw_serde]
+#[derive(Default)]
+pub struct SwapParams {
+    pub fee_bps: u16,
+    pub spread_bps: u16,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    // Guardian only; pauses every operation but withdrawals, or only `operation`
+    EmergencyPause {
+        operation: Option<Operation>,
+    },
+    EmergencyUnpause {
+        operation: Option<Operation>,
+    },
+    // Guardian only; hands the guardian role to another address
+    SetGuardian {
+        guardian: String,
+    },
+    // Admin only; the proposed address becomes admin once it sends `AcceptAdmin`
+    ProposeNewAdmin {
+        new_admin: String,
+    },
+    AcceptAdmin {},
+    // Admin only; `None` removes the role's holder
+    SetRole {
+        role: Role,
+        address: Option<String>,
+    },
+    // Admin only; the pair starts enabled, with no fixed rate set
+    AddPair {
+        pair: PairConfig,
+    },
+    // Admin only. While the pair has a rate delay, a new rate source or a shorter delay waits
+    // out the current delay as the pair's pending update; the other fields apply at once.
+    UpdatePair {
+        base_token: String,
+        update: PairUpdate,
+    },
+    DisablePair {
+        base_token: String,
+    },
+    // Admin only; puts the pair's pending update in place once its `effective_at` has passed
+    ApplyPairUpdate {
+        base_token: String,
+    },
+    // Guardian only; drops the pair's pending update
+    CancelPairUpdate {
+        base_token: String,
+    },
+    // Rate setter or admin. Whole quote tokens per whole base token, e.g. "2345.67"; token
+    // decimals are applied by the contract
+    SetExchangeRate {
+        base_token: String,
+        rate: Decimal,
+    },
+    // Rate setter or admin; announces a rate that takes effect at `effective_at`, at least the
+    // pair's `rate_delay_seconds` from now. Replaces any rate already pending for the pair.
+    ProposeRate {
+        base_token: String,
+        rate: Decimal,
+        effective_at: Timestamp,
+    },
+    // Guardian only; drops the pair's pending rate before it takes effect
+    CancelRate {
+        base_token: String,
+    },
+    // Pauser or admin for a single pair. A `None` pair pauses every swap and is guardian only,
+    // as it sets the same `swap` pause as `EmergencyPause`. Rates are left untouched.
+    Pause {
+        base_token: Option<String>,
+    },
+    Unpause {
+        base_token: Option<String>,
+    },
+    // Admin only
+    AddMarketMaker {
+        address: String,
+    },
+    RemoveMarketMaker {
+        address: String,
+    },
+    // Admin or treasury; sends every collected fee to `to`
+    WithdrawFees {
+        to: String,
+    },
+    // Admin only; withdraws `amount` of `token` from the swap reserve
+    WithdrawReserve {
+        token: String,
+        amount: Uint128,
+        to: String,
+    },
+    // Admin only
+    AddFeeder {
+        address: String,
+    },
+    RemoveFeeder {
+        address: String,
+    },
+    // Admin only
+    AddAuditor {
+        address: String,
+    },
+    RemoveAuditor {
+        address: String,
+    },
+    // Admin only; while set, buying the pair's base token requires attested reserves covering
+    // its whole circulating supply. `None` lifts the requirement.
+    SetReserveBacking {
+        base_token: String,
+        backing: Option<ReserveBacking>,
+    },
+    // Auditors only; replaces the pair's latest attestation. The sender is recorded as auditor.
+    PostAttestation {
+        base_token: String,
+        ounces: Decimal,
+        report_hash: String,
+        reported_at: Timestamp,
+    },
+    // Whitelisted feeders only, on pairs in feeder mode; replaces the sender's previous
+    // submission for the pair. The price must be positive.
+    SubmitPrice {
+        base_token: String,
+        price: Decimal,
+    },
+    // Swaps the single native coin sent along, see `ReceiveMsg::Swap`
+    Swap {
+        ask_token: Option<String>,
+        min_output: Option<Uint128>,
+        deadline: Option<Timestamp>,
+    },
+    // Places a limit order with the single native coin sent along, see `ReceiveMsg::PlaceLimitOrder`
+    PlaceLimitOrder {
+        min_rate: Decimal,
+        expires_at: Option<Timestamp>,
+    },
+    // Owner only; removes an open order and returns its escrow
+    CancelOrder {
+        id: u64,
+    },
+    // Anyone; looks at up to `limit` of the pair's orders whose `min_rate` the current rate
+    // meets, fills them and refunds expired ones among them. Each call continues where the last
+    // one stopped, so orders that cannot fill yet are passed over.
+    ExecuteOrders {
+        base_token: String,
+        limit: Option<u32>,
+    },
+    // Creates a DCA plan with the single native coin sent along, see `ReceiveMsg::CreateDcaPlan`
+    CreateDcaPlan {
+        base_token: String,
+        per_swap_amount: Uint128,
+        interval_seconds: u64,
+        max_swaps: u32,
+    },
+    // Anyone; runs up to `limit` due DCA swaps, earning the pair's keeper tip on each
+    ExecuteDue {
+        limit: Option<u32>,
+    },
+    // Owner only; a paused plan is not run until resumed
+    PauseDcaPlan {
+        id: u64,
+    },
+    ResumeDcaPlan {
+        id: u64,
+    },
+    // Owner only; ends the plan and refunds its unspent deposit
+    CancelDcaPlan {
+        id: u64,
+    },
+    // Admin only; adds the native coins sent along to the swap reserve
+    FundReserve {},
+    Receive(Cw20ReceiveMsg),
+}
+
+// Payload of the `msg` field of a `Cw20ReceiveMsg` sent to this contract
+#[cw_serde]
+pub enum ReceiveMsg {
+    // Swaps the sent token for the other side of its pair. Sending a pair's base token sells it;
+    // sending a quote token buys the base token named by `ask_token`.
+    // Fails if the output, after fees and spread, is below `min_output` or the block time is past `deadline`
+    Swap {
+        ask_token: Option<String>,
+        min_output: Option<Uint128>,
+        deadline: Option<Timestamp>,
+    },
+    // Escrows the sent base token to be sold once its pair's rate is at least `min_rate`.
+    // Filled by `ExecuteOrders` or by the next `SetExchangeRate` that reaches it, at the rate then.
+    // Orders above the pair's volume caps are refused, as they could never fill.
+    PlaceLimitOrder {
+        min_rate: Decimal,
+        expires_at: Option<Timestamp>,
+    },
+    // Deposits the sent quote token to buy `base_token` with `per_swap_amount` of it every
+    // `interval_seconds`, `max_swaps` times. The deposit must cover exactly that many swaps.
+    // The first swap is due right away.
+    CreateDcaPlan {
+        base_token: String,
+        per_swap_amount: Uint128,
+        interval_seconds: u64,
+        max_swaps: u32,
+    },
+    // Admin only; adds the sent token to the reserve that pays out swaps
+    FundReserve {},
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(ConfigResponse)]
+    Config {},
+    #[returns(crate::ownership::OwnershipResponse)]
+    Ownership {},
+    #[returns(crate::pause::PauseInfoResponse)]
+    PauseInfo {},
+    #[returns(PairResponse)]
+    Pair { base_token: String },
+    #[returns(PairsResponse)]
+    Pairs {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    // Latest rate from the pair's source, whether or not it would pass the oracle checks
+    #[returns(ExchangeRateResponse)]
+    ExchangeRate { base_token: String },
+    // Announced rates that have not taken effect yet, across all pairs
+    #[returns(PendingRateResponse)]
+    PendingRate {},
+    // Rate source or rate delay change still waiting out the pair's delay, if any
+    #[returns(Option<PendingPairUpdate>)]
+    PendingPairUpdate { base_token: String },
+    // Admin, scheduled and oracle or feeder rates the pair has had between `start` and `end`,
+    // both inclusive, oldest first. Oracle and feeder rates are recorded as swaps observe them.
+    #[returns(RateHistoryResponse)]
+    RateHistory {
+        base_token: String,
+        start: Option<Timestamp>,
+        end: Option<Timestamp>,
+        limit: Option<u32>,
+    },
+    // Time-weighted average of the recorded rate over the last `window_seconds`
+    #[returns(TwapResponse)]
+    Twap {
+        base_token: String,
+        window_seconds: u64,
+    },
+    // Output of swapping `amount` of the `offer_asset` token at the current rate.
+    // `ask_asset` is only needed when offering a quote token.
+    #[returns(SimulationResponse)]
+    Simulation {
+        offer_asset: String,
+        ask_asset: Option<String>,
+        amount: Uint128,
+    },
+    // Offer needed to receive at least `amount` of the `ask_asset` token.
+    // `offer_asset` is only needed when asking for a quote token.
+    #[returns(ReverseSimulationResponse)]
+    ReverseSimulation {
+        ask_asset: String,
+        offer_asset: Option<String>,
+        amount: Uint128,
+    },
+    // Every whitelisted feeder and its latest submission for the pair
+    #[returns(FeedersResponse)]
+    Feeders { base_token: String },
+    // Base token volume `address` can still swap on the pair before hitting a cap
+    #[returns(RemainingAllowanceResponse)]
+    RemainingAllowance { base_token: String, address: String },
+    // Attested reserves against circulating supply, for every pair with a reserve backing
+    #[returns(ReserveStatusResponse)]
+    ReserveStatus {},
+    // Receipt of a single swap
+    #[returns(Trade)]
+    Trade { id: u64 },
+    // Receipts of every swap made by `user`, oldest first
+    #[returns(TradesResponse)]
+    TradesByUser {
+        user: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(LimitOrder)]
+    Order { id: u64 },
+    // Open orders on the pair by `min_rate`, lowest first
+    #[returns(OrdersResponse)]
+    OrderBook {
+        base_token: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    // Open orders of `user`, oldest first
+    #[returns(OrdersResponse)]
+    OrdersByUser {
+        user: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(DcaPlan)]
+    DcaPlan { id: u64 },
+    // Active and paused DCA plans of `user`, oldest first
+    #[returns(DcaPlansResponse)]
+    DcaPlansByUser {
+        user: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    // Fees collected and not yet withdrawn, per token
+    #[returns(CollectedFeesResponse)]
+    CollectedFees {},
+    // Tokens available to pay out swaps
+    #[returns(ReservesResponse)]
+    Reserves {},
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
+
+#[cw_serde]
+pub enum SudoMsg {
+    // Governance override of the admin-set rate, not subject to the circuit breaker
+    SetExchangeRate { base_token: String, rate: Decimal },
+}
+
+#[cw_serde]
+pub struct ConfigResponse {
+    pub paused: bool, // Whether swaps are paused, by either pause; see `PauseInfo` for details
+}
+
+#[cw_serde]
+pub struct PairResponse {
+    pub base_token: AssetInfo,
+    pub quote_token: AssetInfo,
+    pub base_decimals: u8,
+    pub quote_decimals: u8,
+    pub rate_source: RateSource,
+    pub sell: SwapParams,
+    pub buy: SwapParams,
+    pub swap_fee_bps: u16,
+    pub circuit_breaker: CircuitBreaker,
+    pub volume_limits: VolumeLimits,
+    pub rate_delay_seconds: u64,
+    pub keeper_tip_bps: u16,
+    pub enabled: bool,
+    pub paused: bool, // Set by the pauser or the circuit breaker
+}
+
+#[cw_serde]
+pub struct PairsResponse {
+    pub pairs: Vec<PairResponse>,
+}
+
+#[cw_serde]
+pub struct ExchangeRateResponse {
+    pub rate: Decimal,
+    pub last_updated: Option<Timestamp>,
+}
+
+#[cw_serde]
+pub struct PendingRateResponse {
+    pub rates: Vec<PendingRate>,
+}
+
+#[cw_serde]
+pub struct PendingRate {
+    pub base_token: String,
+    pub rate: Decimal,
+    pub effective_at: Timestamp,
+}
+
+// Changes `UpdatePair` held back on a timelocked pair; `None` fields are left unchanged
+#[cw_serde]
+pub struct PendingPairUpdate {
+    pub rate_source: Option<RateSource>,
+    pub rate_delay_seconds: Option<u64>,
+    pub effective_at: Timestamp,
+}
+
+#[cw_serde]
+pub struct RateHistoryResponse {
+    pub rates: Vec<RatePoint>,
+}
+
+#[cw_serde]
+pub struct RatePoint {
+    pub timestamp: Timestamp, // When the rate took effect
+    pub rate: Decimal,
+}
+
+#[cw_serde]
+pub struct TwapResponse {
+    pub twap: Decimal,
+    pub window_seconds: u64,
+    pub observations: u32, // Recorded rates the average covers
+}
+
+#[cw_serde]
+pub struct SimulationResponse {
+    pub return_amount: Uint128,
+    pub spread_amount: Uint128,
+    pub fee_amount: Uint128,
+    pub protocol_fee_amount: Uint128,
+    pub rate: Decimal,
+}
+
+#[cw_serde]
+pub struct ReverseSimulationResponse {
+    pub offer_amount: Uint128,
+    pub spread_amount: Uint128,
+    pub fee_amount: Uint128,
+    pub protocol_fee_amount: Uint128,
+    pub rate: Decimal,
+}
+
+#[cw_serde]
+pub struct FeedersResponse {
+    pub feeders: Vec<FeederInfo>,
+}
+
+#[cw_serde]
+pub struct FeederInfo {
+    pub address: String,
+    // Latest submission, if the feeder has submitted at all
+    pub price: Option<Decimal>,
+    pub submitted_at: Option<Timestamp>,
+}
+
+#[cw_serde]
+pub struct RemainingAllowanceResponse {
+    pub market_maker: bool,
+    // `None` when the cap is off or the address is a market maker
+    pub daily_remaining: Option<Uint128>,
+    pub epoch_remaining: Option<Uint128>,
+}
+
+#[cw_serde]
+pub struct ReserveStatusResponse {
+    pub pairs: Vec<PairReserveStatus>,
+}
+
+#[cw_serde]
+pub struct PairReserveStatus {
+    pub base_token: String,
+    pub attestation: Option<Attestation>, // Latest one posted
+    pub circulating_supply: Uint128,
+    pub backed_supply: Uint128, // Supply the attested ounces cover, in the token's smallest unit
+    pub backed: bool,           // Whether swaps into the base token are allowed
+}
+
+// Receipt of a completed swap
+#[cw_serde]
+pub struct Trade {
+    pub id: u64,
+    pub user: Addr,
+    pub base_token: String, // Pair the swap went through
+    pub offer: Asset,       // Sent in by the user
+    pub ask: Asset,         // Paid out to the user, after spread and fees
+    pub rate: Decimal,      // Base-to-quote rate the swap executed at
+    pub fee: Asset,         // Swap and protocol fees, taken in the ask token
+    pub timestamp: Timestamp,
+}
+
+#[cw_serde]
+pub struct TradesResponse {
+    pub trades: Vec<Trade>,
+}
+
+// Base tokens escrowed for sale at `min_rate` or better
+#[cw_serde]
+pub struct LimitOrder {
+    pub id: u64,
+    pub owner: Addr,
+    pub base_token: String,
+    pub offer: Asset,
+    pub min_rate: Decimal,
+    pub expires_at: Option<Timestamp>, // Refunded instead of filled from then on
+    pub created_at: Timestamp,
+}
+
+#[cw_serde]
+pub struct OrdersResponse {
+    pub orders: Vec<LimitOrder>,
+}
+
+// Quote tokens deposited up front to buy a base token in equal parts on a schedule
+#[cw_serde]
+pub struct DcaPlan {
+    pub id: u64,
+    pub owner: Addr,
+    pub base_token: String,       // Bought on every swap
+    pub balance: Asset,           // Deposit not yet spent
+    pub per_swap_amount: Uint128, // Spent on each swap, keeper tip included
+    pub interval_seconds: u64,
+    pub max_swaps: u32,
+    pub swaps_done: u32,
+    // A swap its pair cannot take when due is skipped and the next one scheduled
+    pub next_swap_at: Timestamp,
+    pub paused: bool,
+    pub created_at: Timestamp,
+}
+
+#[cw_serde]
+pub struct DcaPlansResponse {
+    pub plans: Vec<DcaPlan>,
+}
+
+#[cw_serde]
+pub struct CollectedFeesResponse {
+    pub fees: Vec<TokenAmount>,
+}
+
+#[cw_serde]
+pub struct TokenAmount {
+    pub token: String, // Denom or contract address
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct ReservesResponse {
+    pub reserves: Vec<TokenAmount>,
+}
@@ -0,0 +1,95 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Uint128;
+use cw20::Cw20ReceiveMsg;
+
+use crate::pause::Operation;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub om_token_address: String, // Address of the OM token contract
+    // Consider adding RWA token addresses if RWAs are tokenized
+    pub guardian: String, // Can pause the pool in an emergency, see `pause`
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    // Pulls `amount` OM from the sender with `TransferFrom`, so the pool must hold an allowance.
+    // OM can also be deposited by sending it with `ReceiveMsg::DepositOmToken`.
+    DepositOmToken {
+        amount: Uint128,
+    },
+    DepositRwaToken {
+        token_id: String,
+        rwa_token_address: String,
+        valuation: Uint128,
+    },
+    // Pays OM out of the sender's own deposits; never paused
+    Withdraw {
+        asset: Asset,
+    },
+    Receive(Cw20ReceiveMsg),
+    // Guardian only; pauses every operation but withdrawals, or only `operation`
+    EmergencyPause {
+        operation: Option<Operation>,
+    },
+    EmergencyUnpause {
+        operation: Option<Operation>,
+    },
+    // Guardian only; hands the guardian role to another address
+    SetGuardian {
+        guardian: String,
+    },
+}
+
+// Hook messages of OM sent to the pool with `Send`
+#[cw_serde]
+pub enum ReceiveMsg {
+    DepositOmToken {},
+}
+
+#[cw_serde]
+pub enum Asset {
+    OmToken(Uint128),
+    RwaToken {
+        token_id: String,
+        rwa_token_address: String,
+    },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(ConfigResponse)]
+    Config {},
+    #[returns(PoolInfoResponse)]
+    PoolInfo {},
+    // OM `address` has deposited and can withdraw
+    #[returns(DepositResponse)]
+    Deposit { address: String },
+    #[returns(crate::pause::PauseInfoResponse)]
+    PauseInfo {},
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
+
+#[cw_serde]
+pub enum SudoMsg {
+    // Governance can repoint the pool at a migrated OM token contract
+    UpdateConfig { om_token_address: String },
+}
+
+#[cw_serde]
+pub struct ConfigResponse {
+    pub om_token_address: String,
+}
+
+#[cw_serde]
+pub struct PoolInfoResponse {
+    pub total_om_tokens: Uint128,
+}
+
+#[cw_serde]
+pub struct DepositResponse {
+    pub om_tokens: Uint128,
+}
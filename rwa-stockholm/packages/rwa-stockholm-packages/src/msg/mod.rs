@@ -0,0 +1,8 @@
+//! Message types for every contract in the workspace, one module per contract.
+//! Each contract crate's `bin/schema.rs` exports its module for client generation.
+
+pub mod gold_swap;
+pub mod liquidity_pool;
+pub mod oracle;
+pub mod rwa_nft;
+pub mod staking;
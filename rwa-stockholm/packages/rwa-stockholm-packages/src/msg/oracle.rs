@@ -0,0 +1,40 @@
+//! Price feed interface gold_swap queries in oracle mode. `QueryMsg` and `PriceResponse` are
+//! what any oracle must answer; the remaining messages belong to `contracts/mock-oracle`.
+
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Decimal, Timestamp};
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub admin: String,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    // Admin only; `publish_time` defaults to the current block time
+    SetPrice {
+        pair: String,
+        price: Decimal,
+        confidence: Decimal,
+        publish_time: Option<Timestamp>,
+    },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    // Latest price for a pair such as "XAU/OM", in whole quote tokens per whole base token
+    #[returns(PriceResponse)]
+    Price { pair: String },
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
+
+#[cw_serde]
+pub struct PriceResponse {
+    pub price: Decimal,
+    // Half-width of the band the true price is expected to lie in, in the same unit as `price`
+    pub confidence: Decimal,
+    pub publish_time: Timestamp,
+}
@@ -0,0 +1,161 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Coin, Empty};
+use cw721::{
+    AllNftInfoResponse, ApprovalResponse, ApprovalsResponse, ContractInfoResponse, Expiration,
+    NftInfoResponse, NumTokensResponse, OperatorsResponse, OwnerOfResponse, TokensResponse,
+};
+use cw721_base::{
+    msg::{ExecuteMsg as Cw721ExecuteMsg, InstantiateMsg as Cw721InstantiateMsg},
+    Extension, MinterResponse,
+};
+
+use crate::pause::Operation;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub cw721_base_msg: Cw721InstantiateMsg,
+    pub guardian: String, // Can pause the marketplace in an emergency, see `pause`
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    Cw721Base(Cw721ExecuteMsg<Extension, Empty>),
+    ListNftForSale {
+        token_id: String,
+        price: Coin,
+    },
+    BuyNft {
+        token_id: String,
+    },
+    // Grant the user role directly, without payment (ERC-4907 `setUser`). `expires` must not be
+    // `Never`.
+    SetUser {
+        token_id: String,
+        user: String,
+        expires: Expiration,
+    },
+    // End a user role granted with `SetUser` early; paid rentals run until they expire
+    RevokeUser {
+        token_id: String,
+    },
+    // Publish the terms under which anyone can rent the token
+    OfferLease {
+        token_id: String,
+        rent_per_period: Coin,
+        period_seconds: u64,
+        max_periods: u64,
+    },
+    CancelLeaseOffer {
+        token_id: String,
+    },
+    // Pay exactly `periods` times the rent and become the user of the token until the lease runs out
+    RentNft {
+        token_id: String,
+        periods: u64,
+    },
+    // Guardian only; pauses every operation but withdrawals, or only `operation`
+    EmergencyPause {
+        operation: Option<Operation>,
+    },
+    EmergencyUnpause {
+        operation: Option<Operation>,
+    },
+    // Guardian only; hands the guardian role to another address
+    SetGuardian {
+        guardian: String,
+    },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    // Standard cw721 queries, answered by the cw721 base
+    #[returns(OwnerOfResponse)]
+    OwnerOf {
+        token_id: String,
+        include_expired: Option<bool>,
+    },
+    #[returns(ApprovalResponse)]
+    Approval {
+        token_id: String,
+        spender: String,
+        include_expired: Option<bool>,
+    },
+    #[returns(ApprovalsResponse)]
+    Approvals {
+        token_id: String,
+        include_expired: Option<bool>,
+    },
+    #[returns(OperatorsResponse)]
+    AllOperators {
+        owner: String,
+        include_expired: Option<bool>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(NftInfoResponse<Extension>)]
+    NftInfo { token_id: String },
+    #[returns(AllNftInfoResponse<Extension>)]
+    AllNftInfo {
+        token_id: String,
+        include_expired: Option<bool>,
+    },
+    #[returns(TokensResponse)]
+    Tokens {
+        owner: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(TokensResponse)]
+    AllTokens {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(NumTokensResponse)]
+    NumTokens {},
+    #[returns(ContractInfoResponse)]
+    ContractInfo {},
+    #[returns(MinterResponse)]
+    Minter {},
+
+    #[returns(ListingResponse)]
+    Listing { token_id: String },
+    #[returns(UserOfResponse)]
+    UserOf { token_id: String },
+    #[returns(LeaseOfferResponse)]
+    LeaseOffer { token_id: String },
+    #[returns(crate::pause::PauseInfoResponse)]
+    PauseInfo {},
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
+
+#[cw_serde]
+pub enum SudoMsg {
+    // Governance can take down a listing, e.g. when the backing asset turns out to be fraudulent
+    RemoveListing { token_id: String },
+}
+
+#[cw_serde]
+pub struct ListingResponse {
+    pub token_id: String,
+    pub seller: String,
+    pub price: Coin,
+}
+
+#[cw_serde]
+pub struct UserOfResponse {
+    // `None` once the lease has expired, even if the record was not cleaned up yet
+    pub user: Option<String>,
+    pub expires: Option<Expiration>,
+}
+
+#[cw_serde]
+pub struct LeaseOfferResponse {
+    pub token_id: String,
+    pub lessor: String,
+    pub rent_per_period: Coin,
+    pub period_seconds: u64,
+    pub max_periods: u64,
+}
@@ -0,0 +1,83 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Uint128;
+use cw721::Cw721ReceiveMsg;
+
+use crate::pause::Operation;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub om_token_address: String,     // Address of the OM token contract
+    pub reward_rate_per_day: Uint128, // Base reward rate per day for staking
+    pub guardian: String,             // Can pause staking in an emergency, see `pause`
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    // Stakes an NFT the sender owns, moving it into the contract. The contract must be approved
+    // to transfer it; otherwise send it with `SendNft`, which stakes it through `ReceiveNft`.
+    StakeNft {
+        nft_contract_address: String,
+        token_id: String,
+    },
+    UnstakeNft {
+        token_id: String,
+    },
+    ClaimRewards {
+        token_id: String,
+    },
+    ReceiveNft(Cw721ReceiveMsg),
+    // Guardian only; pauses every operation but withdrawals, or only `operation`
+    EmergencyPause {
+        operation: Option<Operation>,
+    },
+    EmergencyUnpause {
+        operation: Option<Operation>,
+    },
+    // Guardian only; hands the guardian role to another address
+    SetGuardian {
+        guardian: String,
+    },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(ConfigResponse)]
+    Config {},
+    #[returns(StakedAssetResponse)]
+    StakedAsset { owner: String, token_id: String },
+    #[returns(PendingRewardsResponse)]
+    PendingRewards { owner: String, token_id: String },
+    #[returns(crate::pause::PauseInfoResponse)]
+    PauseInfo {},
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
+
+#[cw_serde]
+pub enum SudoMsg {
+    // Governance can retune the base reward rate
+    UpdateRewardRate { reward_rate_per_day: Uint128 },
+}
+
+#[cw_serde]
+pub struct ConfigResponse {
+    pub om_token_address: String,
+    pub reward_rate_per_day: Uint128,
+    pub total_staked: u64,
+}
+
+#[cw_serde]
+pub struct StakedAssetResponse {
+    pub owner: String,
+    pub nft_contract_address: String,
+    pub token_id: String,
+    pub staked_since: u64,
+}
+
+#[cw_serde]
+pub struct PendingRewardsResponse {
+    pub token_id: String,
+    pub rewards: Uint128,
+}
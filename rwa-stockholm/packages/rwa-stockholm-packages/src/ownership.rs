@@ -0,0 +1,192 @@
+//! Admin ownership with a two-step transfer, plus single-holder roles the admin can hand out.
+//! A contract keeps an [`Ownership`] in its state module and forwards its `ProposeNewAdmin`,
+//! `AcceptAdmin` and `SetRole` messages and its `Ownership` query here.
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Api, Order, Response, StdResult, Storage};
+use cw_storage_plus::{Item, Map};
+
+use crate::ContractError;
+
+// Permissions the admin can delegate. The admin itself passes every role check.
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub enum Role {
+    RateSetter, // Sets exchange rates
+    Pauser,     // Pauses and unpauses single pairs
+    Treasury,   // Withdraws collected fees
+}
+
+impl Role {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Role::RateSetter => "rate_setter",
+            Role::Pauser => "pauser",
+            Role::Treasury => "treasury",
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        [Role::RateSetter, Role::Pauser, Role::Treasury]
+            .into_iter()
+            .find(|role| role.as_str() == key)
+    }
+}
+
+#[cw_serde]
+pub struct OwnershipResponse {
+    pub admin: Addr,
+    // Proposed by the admin, becomes admin once it accepts
+    pub pending_admin: Option<Addr>,
+    pub roles: Vec<RoleHolder>,
+}
+
+#[cw_serde]
+pub struct RoleHolder {
+    pub role: Role,
+    pub address: Addr,
+}
+
+pub struct Ownership<'a> {
+    admin: Item<'a, Addr>,
+    pending_admin: Item<'a, Addr>,
+    roles: Map<'a, &'a str, Addr>,
+}
+
+impl<'a> Ownership<'a> {
+    pub const fn new(admin_key: &'a str, pending_admin_key: &'a str, roles_key: &'a str) -> Self {
+        Self {
+            admin: Item::new(admin_key),
+            pending_admin: Item::new(pending_admin_key),
+            roles: Map::new(roles_key),
+        }
+    }
+
+    /// Validates and stores the first admin, used from `instantiate`.
+    pub fn initialize(
+        &self,
+        storage: &mut dyn Storage,
+        api: &dyn Api,
+        admin: &str,
+    ) -> Result<Addr, ContractError> {
+        let admin = api.addr_validate(admin)?;
+        self.admin.save(storage, &admin)?;
+        Ok(admin)
+    }
+
+    pub fn admin(&self, storage: &dyn Storage) -> StdResult<Addr> {
+        self.admin.load(storage)
+    }
+
+    pub fn assert_admin(&self, storage: &dyn Storage, sender: &Addr) -> Result<(), ContractError> {
+        if *sender != self.admin(storage)? {
+            return Err(ContractError::Unauthorized {});
+        }
+        Ok(())
+    }
+
+    /// Passes for the holder of `role` and for the admin.
+    pub fn assert_role(
+        &self,
+        storage: &dyn Storage,
+        sender: &Addr,
+        role: Role,
+    ) -> Result<(), ContractError> {
+        if self.role(storage, role)?.as_ref() == Some(sender) {
+            return Ok(());
+        }
+        self.assert_admin(storage, sender)
+    }
+
+    pub fn role(&self, storage: &dyn Storage, role: Role) -> StdResult<Option<Addr>> {
+        self.roles.may_load(storage, role.as_str())
+    }
+
+    /// Admin only. Replaces any earlier proposal; the admin stays in charge until accepted.
+    pub fn propose_admin(
+        &self,
+        storage: &mut dyn Storage,
+        api: &dyn Api,
+        sender: &Addr,
+        new_admin: &str,
+    ) -> Result<Response, ContractError> {
+        self.assert_admin(storage, sender)?;
+
+        let new_admin = api.addr_validate(new_admin)?;
+        self.pending_admin.save(storage, &new_admin)?;
+
+        Ok(Response::new()
+            .add_attribute("action", "propose_new_admin")
+            .add_attribute("pending_admin", new_admin))
+    }
+
+    /// Only the proposed admin can accept, which completes the transfer.
+    pub fn accept_admin(
+        &self,
+        storage: &mut dyn Storage,
+        sender: &Addr,
+    ) -> Result<Response, ContractError> {
+        let pending_admin = self
+            .pending_admin
+            .may_load(storage)?
+            .ok_or(ContractError::NoPendingAdmin {})?;
+        if *sender != pending_admin {
+            return Err(ContractError::Unauthorized {});
+        }
+
+        self.admin.save(storage, &pending_admin)?;
+        self.pending_admin.remove(storage);
+
+        Ok(Response::new()
+            .add_attribute("action", "accept_admin")
+            .add_attribute("admin", pending_admin))
+    }
+
+    /// Admin only; `None` leaves the role to the admin alone.
+    pub fn set_role(
+        &self,
+        storage: &mut dyn Storage,
+        api: &dyn Api,
+        sender: &Addr,
+        role: Role,
+        address: Option<String>,
+    ) -> Result<Response, ContractError> {
+        self.assert_admin(storage, sender)?;
+
+        let holder = address
+            .map(|address| api.addr_validate(&address))
+            .transpose()?;
+        match &holder {
+            Some(holder) => self.roles.save(storage, role.as_str(), holder)?,
+            None => self.roles.remove(storage, role.as_str()),
+        }
+
+        Ok(Response::new()
+            .add_attribute("action", "set_role")
+            .add_attribute("role", role.as_str())
+            // Attribute values must not be empty, so a removed holder is reported as "none"
+            .add_attribute(
+                "address",
+                holder.map_or_else(|| "none".to_string(), String::from),
+            ))
+    }
+
+    pub fn query(&self, storage: &dyn Storage) -> StdResult<OwnershipResponse> {
+        let roles = self
+            .roles
+            .range(storage, None, None, Order::Ascending)
+            .filter_map(|item| match item {
+                Ok((key, address)) => {
+                    Role::from_key(&key).map(|role| Ok(RoleHolder { role, address }))
+                }
+                Err(err) => Some(Err(err)),
+            })
+            .collect::<StdResult<Vec<_>>>()?;
+
+        Ok(OwnershipResponse {
+            admin: self.admin(storage)?,
+            pending_admin: self.pending_admin.may_load(storage)?,
+            roles,
+        })
+    }
+}
@@ -0,0 +1,200 @@
+//! Emergency stop with a global pause and a pause per operation, both controlled by a guardian.
+//! A contract keeps a [`Pausable`] in its state module, checks [`Pausable::assert_not_paused`]
+//! in its `execute` dispatcher, and forwards its `EmergencyPause`, `EmergencyUnpause` and
+//! `SetGuardian` messages and its `PauseInfo` query here. A contract with its own pause role
+//! sets the same pauses through [`Pausable::pause`], so there is one pause state to query.
+//!
+//! User withdrawals, unstaking and cancellations are never checked at all, so user funds can
+//! always leave. [`Operation::Withdraw`] only covers admin withdrawals of fees and reserves,
+//! which the global pause leaves alone and which can be paused on their own.
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Api, Empty, Order, Response, StdResult, Storage};
+use cw_storage_plus::{Item, Map};
+
+use crate::ContractError;
+
+// Groups of execute messages that can be paused on their own
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub enum Operation {
+    Swap,
+    Deposit,
+    Withdraw, // Admin fee and reserve withdrawals, only stopped when paused on their own
+    List,
+    Buy,
+    Stake,
+    Claim,
+}
+
+impl Operation {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Operation::Swap => "swap",
+            Operation::Deposit => "deposit",
+            Operation::Withdraw => "withdraw",
+            Operation::List => "list",
+            Operation::Buy => "buy",
+            Operation::Stake => "stake",
+            Operation::Claim => "claim",
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        [
+            Operation::Swap,
+            Operation::Deposit,
+            Operation::Withdraw,
+            Operation::List,
+            Operation::Buy,
+            Operation::Stake,
+            Operation::Claim,
+        ]
+        .into_iter()
+        .find(|operation| operation.as_str() == key)
+    }
+}
+
+#[cw_serde]
+pub struct PauseInfoResponse {
+    pub guardian: Option<Addr>,
+    pub paused: bool, // Global pause, which does not cover withdrawals
+    pub paused_operations: Vec<Operation>,
+}
+
+pub struct Pausable<'a> {
+    guardian: Item<'a, Addr>,
+    paused: Item<'a, bool>,
+    paused_operations: Map<'a, &'a str, Empty>,
+}
+
+impl<'a> Pausable<'a> {
+    pub const fn new(guardian_key: &'a str, paused_key: &'a str, operations_key: &'a str) -> Self {
+        Self {
+            guardian: Item::new(guardian_key),
+            paused: Item::new(paused_key),
+            paused_operations: Map::new(operations_key),
+        }
+    }
+
+    /// Validates and stores the first guardian, used from `instantiate`.
+    pub fn initialize(
+        &self,
+        storage: &mut dyn Storage,
+        api: &dyn Api,
+        guardian: &str,
+    ) -> Result<Addr, ContractError> {
+        let guardian = api.addr_validate(guardian)?;
+        self.guardian.save(storage, &guardian)?;
+        Ok(guardian)
+    }
+
+    pub fn assert_guardian(
+        &self,
+        storage: &dyn Storage,
+        sender: &Addr,
+    ) -> Result<(), ContractError> {
+        if self.guardian.may_load(storage)?.as_ref() != Some(sender) {
+            return Err(ContractError::Unauthorized {});
+        }
+        Ok(())
+    }
+
+    /// Whether `operation` is paused on its own or, unless it is a withdrawal, by the global
+    /// pause.
+    pub fn is_paused(&self, storage: &dyn Storage, operation: Operation) -> StdResult<bool> {
+        let globally =
+            operation != Operation::Withdraw && self.paused.may_load(storage)?.unwrap_or_default();
+        Ok(globally || self.paused_operations.has(storage, operation.as_str()))
+    }
+
+    pub fn assert_not_paused(
+        &self,
+        storage: &dyn Storage,
+        operation: Operation,
+    ) -> Result<(), ContractError> {
+        if self.is_paused(storage, operation)? {
+            return Err(ContractError::OperationPaused {
+                operation: operation.as_str().to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Guardian only. `None` sets the global pause, an operation only its own pause.
+    pub fn set_paused(
+        &self,
+        storage: &mut dyn Storage,
+        sender: &Addr,
+        operation: Option<Operation>,
+        paused: bool,
+    ) -> Result<Response, ContractError> {
+        self.assert_guardian(storage, sender)?;
+        self.pause(storage, operation, paused)?;
+
+        let action = if paused {
+            "emergency_pause"
+        } else {
+            "emergency_unpause"
+        };
+        Ok(Response::new()
+            .add_attribute("action", action)
+            .add_attribute("operation", operation.map_or("all", Operation::as_str)))
+    }
+
+    /// Sets or lifts a pause without checking the sender, for a contract's own pause role.
+    /// `None` is the global pause.
+    pub fn pause(
+        &self,
+        storage: &mut dyn Storage,
+        operation: Option<Operation>,
+        paused: bool,
+    ) -> StdResult<()> {
+        match operation {
+            Some(operation) if paused => {
+                self.paused_operations
+                    .save(storage, operation.as_str(), &Empty {})
+            }
+            Some(operation) => {
+                self.paused_operations.remove(storage, operation.as_str());
+                Ok(())
+            }
+            None => self.paused.save(storage, &paused),
+        }
+    }
+
+    /// Guardian only; hands the role to `new_guardian`.
+    pub fn set_guardian(
+        &self,
+        storage: &mut dyn Storage,
+        api: &dyn Api,
+        sender: &Addr,
+        new_guardian: &str,
+    ) -> Result<Response, ContractError> {
+        self.assert_guardian(storage, sender)?;
+
+        let new_guardian = api.addr_validate(new_guardian)?;
+        self.guardian.save(storage, &new_guardian)?;
+
+        Ok(Response::new()
+            .add_attribute("action", "set_guardian")
+            .add_attribute("guardian", new_guardian))
+    }
+
+    pub fn query(&self, storage: &dyn Storage) -> StdResult<PauseInfoResponse> {
+        let paused_operations = self
+            .paused_operations
+            .keys(storage, None, None, Order::Ascending)
+            .filter_map(|key| match key {
+                Ok(key) => Operation::from_key(&key).map(Ok),
+                Err(err) => Some(Err(err)),
+            })
+            .collect::<StdResult<Vec<_>>>()?;
+
+        Ok(PauseInfoResponse {
+            guardian: self.guardian.may_load(storage)?,
+            paused: self.paused.may_load(storage)?.unwrap_or_default(),
+            paused_operations,
+        })
+    }
+}